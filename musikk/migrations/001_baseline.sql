@@ -0,0 +1,105 @@
+-- the schema as it was when migrations were introduced. older databases may already have some or
+-- all of it, so everything is IF NOT EXISTS. later changes go in their own numbered migration
+
+CREATE TABLE IF NOT EXISTS tracks (
+  spotify_id TEXT PRIMARY KEY,
+  recco_id TEXT,
+  name TEXT NOT NULL,
+  artists TEXT,
+  album_id TEXT,
+  album_name TEXT,
+  duration_ms INTEGER,
+  popularity INTEGER,
+  sources TEXT,
+  genres TEXT,
+  tempo REAL,
+  key INTEGER,
+  mode INTEGER,
+  danceability REAL,
+  energy REAL,
+  valence REAL,
+  acousticness REAL,
+  instrumentalness REAL,
+  speechiness REAL,
+  liveness REAL,
+  loudness REAL,
+  unavailable INTEGER DEFAULT 0,
+  unavailable_reason TEXT,
+  unavailable_since TEXT,
+  feature_status TEXT DEFAULT 'pending',
+  feature_attempted_at TEXT,
+  feature_attempts INTEGER DEFAULT 0,
+  first_seen TEXT,
+  last_seen TEXT,
+  updated TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_tempo ON tracks(tempo);
+CREATE INDEX IF NOT EXISTS idx_energy ON tracks(energy);
+CREATE INDEX IF NOT EXISTS idx_danceability ON tracks(danceability);
+CREATE INDEX IF NOT EXISTS idx_valence ON tracks(valence);
+CREATE INDEX IF NOT EXISTS idx_key ON tracks(key);
+CREATE INDEX IF NOT EXISTS idx_key_mode ON tracks(key, mode);
+CREATE INDEX IF NOT EXISTS idx_acousticness ON tracks(acousticness);
+CREATE INDEX IF NOT EXISTS idx_instrumentalness ON tracks(instrumentalness);
+CREATE INDEX IF NOT EXISTS idx_loudness ON tracks(loudness);
+CREATE INDEX IF NOT EXISTS idx_popularity ON tracks(popularity);
+CREATE INDEX IF NOT EXISTS idx_duration ON tracks(duration_ms);
+
+-- search index over the text columns, rowid is the tracks rowid.
+-- artists and genres are indexed as plain words, not json.
+-- VACUUM can renumber tracks rowids, rebuild the index after one
+CREATE VIRTUAL TABLE IF NOT EXISTS tracks_fts USING fts5(
+  name,
+  artists,
+  album_name,
+  genres,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TABLE IF NOT EXISTS playlists (
+  id TEXT PRIMARY KEY,
+  name TEXT NOT NULL,
+  snapshot_id TEXT,
+  owner TEXT,
+  description TEXT,
+  updated TEXT
+);
+
+CREATE TABLE IF NOT EXISTS playlist_tracks (
+  playlist_id TEXT NOT NULL,
+  spotify_id TEXT NOT NULL,
+  position INTEGER NOT NULL,
+  added_at TEXT,
+  PRIMARY KEY (playlist_id, position)
+);
+
+CREATE INDEX IF NOT EXISTS idx_playlist_tracks_track ON playlist_tracks(spotify_id);
+
+-- saved filters that get written to a spotify playlist after every sync
+CREATE TABLE IF NOT EXISTS smart_playlists (
+  id INTEGER PRIMARY KEY,
+  name TEXT NOT NULL,
+  filter TEXT NOT NULL,
+  spotify_playlist_id TEXT,
+  track_count INTEGER,
+  last_published TEXT,
+  last_error TEXT,
+  created TEXT,
+  updated TEXT
+);
+
+CREATE TABLE IF NOT EXISTS sync_log (
+  id INTEGER PRIMARY KEY,
+  started_at TEXT,
+  finished_at TEXT,
+  tracks_added INTEGER,
+  tracks_updated INTEGER,
+  tracks_unavailable INTEGER,
+  error TEXT
+);
+
+CREATE TABLE IF NOT EXISTS config (
+  key TEXT PRIMARY KEY,
+  value TEXT
+);
@@ -1,35 +1,107 @@
 use axum::{
     Router,
+    middleware,
     routing::{get, post},
     extract::{Path, Query, State},
-    response::{Json, IntoResponse},
+    response::{
+        sse::{Event as SseEvent, KeepAlive, Sse},
+        IntoResponse, Json, Response,
+    },
     http::StatusCode,
 };
 use rusqlite::Connection;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
 use tower_http::services::ServeDir;
-use tower_http::cors::{CorsLayer, Any};
 
-use crate::db::{self, TrackFilter};
+use crate::auth::{self, AuthConfig};
+use crate::autodj;
+use crate::camelot::Camelot;
+use crate::db::{self, FeatureRange, Track, TrackFilter};
+use crate::djset::{self, SetOptions};
+use crate::events::{EventBus, SyncProgress};
+use crate::playlist;
+use crate::pool::{DbPool, DEFAULT_POOL_SIZE};
+use crate::schedule::{self, Schedule};
+use crate::similar::{self, Weights};
 use crate::spotify::SpotifyClient;
 use crate::sync;
 
+// refresh this long before spotify says the token expires
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
 #[derive(Clone)]
 pub struct AppState {
     pub db_path: PathBuf,
+    // handlers and playlist pushes go through this, syncs open their own connections
+    pub db: DbPool,
     pub spotify_client_id: String,
     pub spotify_client_secret: String,
+    pub auth: AuthConfig,
+    // shared so every request, syncs included, goes through the same rate limit state
+    pub spotify: SpotifyClient,
+    token: Arc<Mutex<Option<CachedToken>>>,
+    pub autodj: Arc<Mutex<autodj::Session>>,
+    pub events: EventBus,
+    // held while a sync runs so only one runs at a time
+    pub sync: sync::SyncTracker,
+    // run syncs in the background on this schedule
+    pub sync_schedule: Option<Schedule>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+impl AppState {
+    pub fn new(db_path: PathBuf, spotify_client_id: String, spotify_client_secret: String) -> Self {
+        let spotify = SpotifyClient::new(
+            spotify_client_id.clone(),
+            spotify_client_secret.clone(),
+            "http://127.0.0.1:1670/callback".to_string(),
+        );
+        Self {
+            db: DbPool::new(db_path.clone(), DEFAULT_POOL_SIZE),
+            db_path,
+            spotify_client_id,
+            spotify_client_secret,
+            auth: AuthConfig::default(),
+            spotify,
+            token: Arc::new(Mutex::new(None)),
+            autodj: Arc::new(Mutex::new(autodj::Session::default())),
+            events: EventBus::new(),
+            sync: sync::SyncTracker::default(),
+            sync_schedule: None,
+        }
+    }
 }
 
 pub async fn serve(state: AppState, port: u16) {
+    // make sure the schema is up to date before handlers open their own connections
+    db::open_db(&state.db_path).expect("failed to open db");
     let shared = Arc::new(state);
 
-    let app = Router::new()
-        .route("/api/tracks", get(get_tracks))
-        .route("/api/tracks/:id", get(get_track))
-        .route("/api/meta", get(get_meta))
+    if shared.auth.api_token.is_none() {
+        println!("warning: no MUSIKK_API_TOKEN set, player and sync endpoints are open to anyone");
+    }
+    // static/ is build output and not in git
+    if !std::path::Path::new("static/index.html").exists() {
+        println!("warning: static/index.html not found, run `cd web && pnpm build` for the web ui");
+    }
+    tokio::spawn(autodj::run(shared.clone()));
+    tokio::spawn(crate::events::watch_player(shared.clone()));
+    if let Some(s) = shared.sync_schedule.clone() {
+        tokio::spawn(schedule::run(shared.clone(), s));
+    }
+
+    // playback, sync and spotify auth need the api token
+    let protected = Router::new()
         .route("/api/player", get(get_player))
         .route("/api/player/play/:id", post(play_track))
         .route("/api/player/queue/:id", post(queue_track))
@@ -38,10 +110,32 @@ pub async fn serve(state: AppState, port: u16) {
         .route("/api/player/next", post(skip_next))
         .route("/api/player/prev", post(skip_prev))
         .route("/api/player/seek/:position", post(seek_player))
-        .route("/api/sync", post(trigger_sync))
+        .route("/api/autodj", get(get_autodj))
+        .route("/api/autodj/start", post(start_autodj))
+        .route("/api/autodj/stop", post(stop_autodj))
+        .route("/api/sync", get(sync_status).post(trigger_sync))
+        .route("/api/sync/history", get(sync_history))
+        .route("/api/events", get(event_stream))
+        .route("/api/playlists", post(create_playlist))
+        .route("/api/smart-playlists", get(list_smart_playlists).post(create_smart_playlist))
+        .route(
+            "/api/smart-playlists/:id",
+            get(get_smart_playlist).put(update_smart_playlist).delete(delete_smart_playlist),
+        )
         .route("/callback", get(auth_callback))
+        .route_layer(middleware::from_fn_with_state(shared.clone(), auth::require_auth));
+
+    let app = Router::new()
+        .route("/api/tracks", get(get_tracks))
+        .route("/api/tracks/:id", get(get_track))
+        .route("/api/tracks/:id/similar", get(get_similar))
+        .route("/api/meta", get(get_meta))
+        .route("/api/sets", post(build_set))
+        .route("/login", get(auth::login_page).post(auth::login))
+        .route("/logout", post(auth::logout))
+        .merge(protected)
         .nest_service("/", ServeDir::new("static").append_index_html_on_directories(true))
-        .layer(CorsLayer::new().allow_origin(Any).allow_methods(Any))
+        .layer(shared.auth.cors_layer())
         .with_state(shared);
 
     let addr = format!("0.0.0.0:{}", port);
@@ -51,102 +145,400 @@ pub async fn serve(state: AppState, port: u16) {
     axum::serve(listener, app).await.unwrap();
 }
 
+// runs db work for a handler on the pool, not getting a connection is a 500
+async fn with_db<F>(state: &AppState, f: F) -> Response
+where
+    F: FnOnce(&Connection) -> Response + Send + 'static,
+{
+    match state.db.run(f).await {
+        Ok(response) => response,
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e}))).into_response(),
+    }
+}
+
 #[derive(Deserialize)]
 struct TracksQuery {
-    tempo_min: Option<f64>,
-    tempo_max: Option<f64>,
-    energy_min: Option<f64>,
-    energy_max: Option<f64>,
-    danceability_min: Option<f64>,
-    danceability_max: Option<f64>,
-    valence_min: Option<f64>,
-    valence_max: Option<f64>,
+    tempo: Option<f64>,
+    tolerance_pct: Option<f64>,
+    octave: Option<bool>,
     key: Option<i64>,
+    mode: Option<i64>,
+    // camelot code like 8A, or a spotify id to match that track's key
+    compatible: Option<String>,
     search: Option<String>,
     sources: Option<String>,
+    playlists: Option<String>,
     genres: Option<String>,
     sort: Option<String>,
     limit: Option<i64>,
+    // opaque, taken from next_cursor of the previous page
+    cursor: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TrackResponse {
+    #[serde(flatten)]
+    track: Track,
+    camelot: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    playlists: Option<Vec<db::PlaylistRef>>,
+}
+
+impl From<Track> for TrackResponse {
+    fn from(track: Track) -> Self {
+        let camelot = track.camelot().map(|c| c.to_string());
+        Self { track, camelot, playlists: None }
+    }
+}
+
+// tracks with the playlists they're in, for the track list
+fn with_playlists(conn: &Connection, tracks: Vec<Track>) -> rusqlite::Result<Vec<TrackResponse>> {
+    let ids: Vec<String> = tracks.iter().map(|t| t.spotify_id.clone()).collect();
+    let mut playlists = db::get_track_playlists(conn, &ids)?;
+    Ok(tracks
+        .into_iter()
+        .map(|track| {
+            let in_playlists = playlists.remove(&track.spotify_id).unwrap_or_default();
+            TrackResponse { playlists: Some(in_playlists), ..TrackResponse::from(track) }
+        })
+        .collect())
+}
+
+#[derive(Serialize)]
+struct TracksPage {
+    tracks: Vec<TrackResponse>,
+    total: i64,
+    next_cursor: Option<String>,
+    filter: TrackFilter,
+}
+
+// a camelot code as-is, anything else is looked up as a track id
+fn resolve_camelot(conn: &Connection, s: &str) -> Result<Camelot, String> {
+    if let Some(c) = Camelot::parse(s) {
+        return Ok(c);
+    }
+    let track = db::get_track(conn, s)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("'{}' is neither a camelot code nor a known track", s))?;
+    track.camelot().ok_or_else(|| format!("track {} has no key", s))
+}
+
+// <column>_min / <column>_max for any of db::RANGE_COLUMNS
+fn parse_ranges(params: &HashMap<String, String>) -> Result<Vec<FeatureRange>, String> {
+    let mut ranges: Vec<FeatureRange> = vec![];
+    for (name, value) in params {
+        let (column, is_min) = match (name.strip_suffix("_min"), name.strip_suffix("_max")) {
+            (Some(c), _) => (c, true),
+            (_, Some(c)) => (c, false),
+            _ => continue,
+        };
+        if !db::RANGE_COLUMNS.contains(&column) {
+            return Err(format!("unknown range filter '{}'", name));
+        }
+        let v: f64 = value.parse().map_err(|_| format!("invalid number for {}: '{}'", name, value))?;
+
+        let range = match ranges.iter_mut().find(|r| r.column == column) {
+            Some(r) => r,
+            None => {
+                ranges.push(FeatureRange { column: column.to_string(), min: None, max: None });
+                ranges.last_mut().unwrap()
+            }
+        };
+        if is_min {
+            range.min = Some(v);
+        } else {
+            range.max = Some(v);
+        }
+    }
+    Ok(ranges)
+}
+
+// a zero or negative bpm or tolerance would turn the tempo match into an empty or inverted range
+fn check_positive(name: &str, value: Option<f64>) -> Result<(), String> {
+    match value {
+        Some(v) if !v.is_finite() || v <= 0.0 => Err(format!("{} must be a positive number, got {}", name, v)),
+        _ => Ok(()),
+    }
 }
 
 async fn get_tracks(
     State(state): State<Arc<AppState>>,
     Query(q): Query<TracksQuery>,
+    Query(raw): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    let conn = match Connection::open(&state.db_path) {
-        Ok(c) => c,
-        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+    let ranges = match parse_ranges(&raw) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response(),
     };
+    if let Some(ref sort) = q.sort {
+        if let Err(e) = db::parse_sort(sort) {
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+        }
+    }
+    if let Err(e) = check_positive("tempo", q.tempo).and_then(|_| check_positive("tolerance_pct", q.tolerance_pct)) {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+    // both only shape the tempo match, on their own they'd silently do nothing
+    if q.tempo.is_none() && (q.tolerance_pct.is_some() || q.octave.is_some()) {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "tolerance_pct and octave need tempo"}))).into_response();
+    }
 
-    let filter = TrackFilter {
-        tempo_min: q.tempo_min,
-        tempo_max: q.tempo_max,
-        energy_min: q.energy_min,
-        energy_max: q.energy_max,
-        danceability_min: q.danceability_min,
-        danceability_max: q.danceability_max,
-        valence_min: q.valence_min,
-        valence_max: q.valence_max,
-        key: q.key,
-        search: q.search,
-        sources: q.sources.map(|s| s.split(',').map(|x| x.to_string()).collect()),
-        genres: q.genres.map(|s| s.split(',').map(|x| x.to_string()).collect()),
-        sort: q.sort,
-        limit: q.limit,
-    };
+    with_db(&state, move |conn| {
+        let key_modes = match q.compatible.as_deref() {
+            Some(c) => match resolve_camelot(conn, c) {
+                Ok(camelot) => Some(camelot.compatible().iter().map(|c| c.key_mode()).collect()),
+                Err(e) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response(),
+            },
+            None => None,
+        };
 
-    match db::query_tracks(&conn, &filter) {
-        Ok(tracks) => Json(tracks).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
-    }
+        let filter = TrackFilter {
+            ranges,
+            tempo: q.tempo,
+            tempo_tolerance_pct: q.tolerance_pct,
+            tempo_octave: q.octave.unwrap_or(false),
+            key: q.key,
+            mode: q.mode,
+            key_modes,
+            search: q.search,
+            sources: q.sources.map(|s| s.split(',').map(|x| x.to_string()).collect()),
+            playlists: q.playlists.map(|s| s.split(',').map(|x| x.to_string()).collect()),
+            genres: q.genres.map(|s| s.split(',').map(|x| x.to_string()).collect()),
+            sort: q.sort,
+            limit: q.limit,
+            offset: match q.cursor.as_deref().map(str::parse::<i64>) {
+                Some(Ok(offset)) if offset >= 0 => Some(offset),
+                Some(_) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "invalid cursor"}))).into_response(),
+                None => None,
+            },
+        };
+
+        let result = db::count_tracks(conn, &filter)
+            .and_then(|total| Ok((total, with_playlists(conn, db::query_tracks(conn, &filter)?)?)));
+        match result {
+            Ok((total, tracks)) => {
+                let next = filter.offset.unwrap_or(0) + tracks.len() as i64;
+                Json(TracksPage {
+                    next_cursor: (next < total && !tracks.is_empty()).then(|| next.to_string()),
+                    total,
+                    tracks,
+                    filter,
+                })
+                .into_response()
+            }
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
 }
 
 async fn get_track(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
-    let conn = match Connection::open(&state.db_path) {
-        Ok(c) => c,
-        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+    with_db(&state, move |conn| {
+        match db::get_track(conn, &id).and_then(|t| t.map(|t| with_playlists(conn, vec![t])).transpose()) {
+            Ok(Some(mut tracks)) => Json(tracks.remove(0)).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "track not found"}))).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+struct SimilarQuery {
+    // "energy:2,tempo:0.5", merged over the default weights
+    weights: Option<String>,
+    // only harmonically compatible keys
+    compatible: Option<bool>,
+    // only tracks within this many percent of the seed's bpm
+    tolerance_pct: Option<f64>,
+    octave: Option<bool>,
+    sources: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct SimilarTrack {
+    #[serde(flatten)]
+    track: TrackResponse,
+    distance: f64,
+}
+
+async fn get_similar(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SimilarQuery>,
+) -> impl IntoResponse {
+    let weights = match q.weights.as_deref().map(Weights::parse).unwrap_or_else(|| Ok(Weights::default())) {
+        Ok(w) => w,
+        Err(e) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response(),
     };
+    if let Err(e) = check_positive("tolerance_pct", q.tolerance_pct) {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
 
-    match db::get_track(&conn, &id) {
-        Ok(Some(track)) => Json(track).into_response(),
-        Ok(None) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "track not found"}))).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+    with_db(&state, move |conn| {
+        let seed = match db::get_track(conn, &id) {
+            Ok(Some(track)) => track,
+            Ok(None) => return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "track not found"}))).into_response(),
+            Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        };
+
+        let key_modes = if q.compatible.unwrap_or(false) {
+            match seed.camelot() {
+                Some(c) => Some(c.compatible().iter().map(|c| c.key_mode()).collect()),
+                None => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "track has no key"}))).into_response(),
+            }
+        } else {
+            None
+        };
+        let tempo = match (q.tolerance_pct, seed.tempo) {
+            (Some(_), None) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "track has no tempo"}))).into_response(),
+            (Some(_), tempo) => tempo,
+            (None, _) => None,
+        };
+
+        let filter = TrackFilter {
+            tempo,
+            tempo_tolerance_pct: q.tolerance_pct,
+            tempo_octave: q.octave.unwrap_or(false),
+            key_modes,
+            sources: q.sources.map(|s| s.split(',').map(|x| x.to_string()).collect()),
+            ..Default::default()
+        };
+        let candidates = match db::query_all_tracks(conn, &filter) {
+            Ok(t) => t,
+            Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        };
+
+        match similar::rank(&seed, candidates, &weights, q.limit.unwrap_or(20).clamp(1, 100)) {
+            Ok(ranked) => Json(serde_json::json!({
+                "seed": TrackResponse::from(seed),
+                "tracks": ranked
+                    .into_iter()
+                    .map(|(track, distance)| SimilarTrack { track: TrackResponse::from(track), distance })
+                    .collect::<Vec<_>>(),
+            }))
+            .into_response(),
+            Err(e) => (StatusCode::UNPROCESSABLE_ENTITY, Json(serde_json::json!({"error": e}))).into_response(),
+        }
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+struct SetRequest {
+    #[serde(flatten)]
+    options: SetOptions,
+    genres: Option<Vec<String>>,
+    sources: Option<Vec<String>>,
+    playlists: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct SetTrackResponse {
+    #[serde(flatten)]
+    track: TrackResponse,
+    start_ms: i64,
+    target_tempo: f64,
+    target_energy: f64,
+}
+
+async fn build_set(State(state): State<Arc<AppState>>, Json(req): Json<SetRequest>) -> impl IntoResponse {
+    let mut options = req.options;
+    if let Err(e) = options.validate() {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
     }
+
+    with_db(&state, move |conn| {
+        let filter = TrackFilter {
+            genres: req.genres,
+            sources: req.sources,
+            playlists: req.playlists,
+            ..Default::default()
+        };
+        let pool = match db::query_all_tracks(conn, &filter) {
+            Ok(t) => t,
+            Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        };
+
+        match djset::build_set(pool, &options) {
+            Ok(set) => Json(serde_json::json!({
+                "duration_ms": set.duration_ms,
+                "tracks": set
+                    .tracks
+                    .into_iter()
+                    .map(|e| SetTrackResponse {
+                        track: TrackResponse::from(e.track),
+                        start_ms: e.start_ms,
+                        target_tempo: e.target_tempo,
+                        target_energy: e.target_energy,
+                    })
+                    .collect::<Vec<_>>(),
+                "transitions": set.transitions,
+            }))
+            .into_response(),
+            Err(e) => (StatusCode::UNPROCESSABLE_ENTITY, Json(serde_json::json!({"error": e}))).into_response(),
+        }
+    })
+    .await
 }
 
 async fn get_meta(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let conn = match Connection::open(&state.db_path) {
-        Ok(c) => c,
-        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
-    };
+    with_db(&state, move |conn| {
+        let stats = db::get_stats(conn).ok();
+        let sources = db::get_all_sources(conn).unwrap_or_default();
+        let playlists = db::get_playlists(conn).unwrap_or_default();
+        let genres = db::get_all_genres(conn).unwrap_or_default();
+
+        Json(serde_json::json!({
+            "stats": stats,
+            "sources": sources,
+            "playlists": playlists,
+            "genres": genres
+        })).into_response()
+    })
+    .await
+}
 
-    let stats = db::get_stats(&conn).ok();
-    let sources = db::get_all_sources(&conn).unwrap_or_default();
-    let genres = db::get_all_genres(&conn).unwrap_or_default();
+// hands out a client with a valid access token, only hitting the token endpoint
+// when the cached one is about to expire
+pub(crate) async fn get_spotify_client(state: &AppState) -> Result<SpotifyClient, String> {
+    let mut cached = state.token.lock().await;
 
-    Json(serde_json::json!({
-        "stats": stats,
-        "sources": sources,
-        "genres": genres
-    })).into_response()
-}
+    if let Some(token) = cached.as_ref() {
+        if Instant::now() + TOKEN_REFRESH_MARGIN < token.expires_at {
+            let mut spotify = state.spotify.clone();
+            spotify.set_access_token(token.access_token.clone());
+            return Ok(spotify);
+        }
+    }
 
-async fn get_spotify_client(state: &AppState) -> Result<SpotifyClient, String> {
-    let conn = Connection::open(&state.db_path).map_err(|e| e.to_string())?;
-    let refresh_token = db::get_config(&conn, "spotify_refresh_token")
+    let refresh_token = state
+        .db
+        .run(|conn| db::get_config(conn, "spotify_refresh_token"))
+        .await?
         .map_err(|e| e.to_string())?
         .ok_or("no refresh token")?;
-    
-    let mut spotify = SpotifyClient::new(
-        state.spotify_client_id.clone(),
-        state.spotify_client_secret.clone(),
-        "http://127.0.0.1:1670/callback".to_string(),
-    );
-    
-    spotify.refresh_token(&refresh_token).await?;
+
+    let mut spotify = state.spotify.clone();
+    let token = spotify.refresh_token(&refresh_token).await?;
+
+    // spotify may rotate the refresh token, the old one stops working
+    if let Some(new_refresh) = token.refresh_token {
+        state
+            .db
+            .run(move |conn| db::set_config(conn, "spotify_refresh_token", &new_refresh))
+            .await?
+            .map_err(|e| e.to_string())?;
+    }
+
+    *cached = Some(CachedToken {
+        access_token: token.access_token,
+        expires_at: Instant::now() + Duration::from_secs(token.expires_in.max(0) as u64),
+    });
     Ok(spotify)
 }
 
@@ -252,6 +644,180 @@ struct CallbackQuery {
     code: String,
 }
 
+#[derive(Deserialize)]
+struct CreatePlaylistRequest {
+    #[serde(default)]
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    public: bool,
+    // write to this playlist instead of the one called `name`
+    spotify_playlist_id: Option<String>,
+    // overwrite a same-named playlist that musikk didn't create
+    #[serde(default)]
+    replace: bool,
+    // the `filter` from an /api/tracks response
+    #[serde(default)]
+    filter: TrackFilter,
+}
+
+async fn create_playlist(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CreatePlaylistRequest>,
+) -> impl IntoResponse {
+    if req.name.trim().is_empty() && req.spotify_playlist_id.is_none() {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "playlist name is required"}))).into_response();
+    }
+    if let Some(Err(e)) = req.filter.sort.as_deref().map(db::parse_sort) {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+
+    let spotify = match get_spotify_client(&state).await {
+        Ok(s) => s,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e}))).into_response(),
+    };
+    let target = playlist::PushTarget {
+        name: req.name,
+        description: req.description,
+        public: req.public,
+        playlist_id: req.spotify_playlist_id,
+        replace: req.replace,
+    };
+    match playlist::push_filter(&state.db, &spotify, &target, &req.filter).await {
+        Ok(result) => Json(result).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e}))).into_response(),
+    }
+}
+
+#[derive(Deserialize)]
+struct SmartPlaylistRequest {
+    name: String,
+    #[serde(default)]
+    filter: TrackFilter,
+    // write to an existing playlist instead of creating one on the next sync
+    spotify_playlist_id: Option<String>,
+}
+
+impl SmartPlaylistRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if let Some(Err(e)) = self.filter.sort.as_deref().map(db::parse_sort) {
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+async fn list_smart_playlists(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    with_db(&state, move |conn| {
+        match db::get_smart_playlists(conn) {
+            Ok(playlists) => Json(playlists).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+async fn get_smart_playlist(State(state): State<Arc<AppState>>, Path(id): Path<i64>) -> impl IntoResponse {
+    with_db(&state, move |conn| {
+        match db::get_smart_playlist(conn, id) {
+            Ok(Some(playlist)) => Json(playlist).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "smart playlist not found"}))).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+async fn create_smart_playlist(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<SmartPlaylistRequest>,
+) -> impl IntoResponse {
+    if let Err(e) = req.validate() {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+    with_db(&state, move |conn| {
+        let result = db::insert_smart_playlist(conn, req.name.trim(), &req.filter, req.spotify_playlist_id.as_deref())
+            .and_then(|id| db::get_smart_playlist(conn, id));
+        match result {
+            Ok(Some(playlist)) => (StatusCode::CREATED, Json(playlist)).into_response(),
+            Ok(None) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "smart playlist vanished"}))).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+async fn update_smart_playlist(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i64>,
+    Json(req): Json<SmartPlaylistRequest>,
+) -> impl IntoResponse {
+    if let Err(e) = req.validate() {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+    with_db(&state, move |conn| {
+        let result = db::update_smart_playlist(conn, id, req.name.trim(), &req.filter, req.spotify_playlist_id.as_deref())
+            .and_then(|_| db::get_smart_playlist(conn, id));
+        match result {
+            Ok(Some(playlist)) => Json(playlist).into_response(),
+            Ok(None) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "smart playlist not found"}))).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+// only forgets the filter, the spotify playlist stays as it is
+async fn delete_smart_playlist(State(state): State<Arc<AppState>>, Path(id): Path<i64>) -> impl IntoResponse {
+    with_db(&state, move |conn| {
+        match db::delete_smart_playlist(conn, id) {
+            Ok(true) => Json(serde_json::json!({"status": "deleted"})).into_response(),
+            Ok(false) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "smart playlist not found"}))).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+        }
+    })
+    .await
+}
+
+// player changes and sync progress as server-sent events, `event:` is "player" or "sync"
+async fn event_stream(
+    State(state): State<Arc<AppState>>,
+) -> Sse<impl Stream<Item = Result<SseEvent, axum::Error>>> {
+    let (last_player, rx) = state.events.subscribe();
+    let live = BroadcastStream::new(rx).filter_map(|e| e.ok());
+    let stream = tokio_stream::iter(last_player)
+        .chain(live)
+        .map(|e| SseEvent::default().event(e.name()).json_data(&e));
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+async fn get_autodj(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(state.autodj.lock().await.clone())
+}
+
+// starts a new session, tracks from an earlier one can come up again
+async fn start_autodj(
+    State(state): State<Arc<AppState>>,
+    body: Option<Json<autodj::StartRequest>>,
+) -> impl IntoResponse {
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+    if let Some(Err(e)) = req.filter.sort.as_deref().map(db::parse_sort) {
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+    let mut session = state.autodj.lock().await;
+    session.start(req);
+    Json(session.clone()).into_response()
+}
+
+async fn stop_autodj(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let mut session = state.autodj.lock().await;
+    session.stop();
+    Json(session.clone())
+}
+
 async fn auth_callback(
     State(state): State<Arc<AppState>>,
     Query(q): Query<CallbackQuery>,
@@ -265,10 +831,10 @@ async fn auth_callback(
     match spotify.exchange_code(&q.code).await {
         Ok(token) => {
             if let Some(refresh_token) = token.refresh_token {
-                if let Ok(conn) = Connection::open(&state.db_path) {
-                    let _ = db::set_config(&conn, "spotify_refresh_token", &refresh_token);
-                }
+                let _ = state.db.run(move |conn| db::set_config(conn, "spotify_refresh_token", &refresh_token)).await;
             }
+            // new grant, don't keep handing out the old access token
+            *state.token.lock().await = None;
             axum::response::Html("<h1>auth complete!</h1><p>you can close this tab.</p>").into_response()
         }
         Err(e) => {
@@ -278,44 +844,64 @@ async fn auth_callback(
 }
 
 async fn trigger_sync(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let guard = match state.sync.try_start() {
+        Some(g) => g,
+        None => return (StatusCode::CONFLICT, Json(serde_json::json!({"error": "sync already running"}))).into_response(),
+    };
     let db_path = state.db_path.clone();
-    let client_id = state.spotify_client_id.clone();
-    let client_secret = state.spotify_client_secret.clone();
+    // shares the rate limit with the player, autodj and playlist routes
+    let spotify = state.spotify.clone();
+    let progress = SyncProgress::new(Some(state.events.clone())).with_status(guard.status());
 
-    // spawn sync in background since it takes a while
+    // spawn sync in background since it takes a while, the guard holds the lock until it's done
     tokio::spawn(async move {
-        let log_id = {
-            let conn = match Connection::open(&db_path) {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!("sync failed to open db: {}", e);
-                    return;
-                }
-            };
-            db::start_sync_log(&conn).unwrap_or(0)
-        };
+        let _guard = guard;
+        let _ = sync::run_logged_sync(&db_path, &spotify, &sync::SyncOptions::default(), &progress).await;
+    });
 
-        let mut spotify = SpotifyClient::new(
-            client_id,
-            client_secret,
-            "http://127.0.0.1:1670/callback".to_string(),
-        );
+    Json(serde_json::json!({"status": "sync started"})).into_response()
+}
 
-        match sync::run_sync(&db_path, &mut spotify, false, false).await {
-            Ok(result) => {
-                if let Ok(conn) = Connection::open(&db_path) {
-                    let _ = db::finish_sync_log(&conn, log_id, result.added, result.updated, result.unavailable, None);
-                }
-                println!("sync complete: {} added, {} updated", result.added, result.updated);
-            }
-            Err(e) => {
-                if let Ok(conn) = Connection::open(&db_path) {
-                    let _ = db::finish_sync_log(&conn, log_id, 0, 0, 0, Some(&e));
-                }
-                eprintln!("sync failed: {}", e);
+// what the current sync is doing, and how the last logged one went
+async fn sync_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let status = state.sync.status();
+    with_db(&state, move |conn| match db::get_sync_logs(conn, 1, 0) {
+        Ok(logs) => Json(serde_json::json!({"status": status, "last": logs.into_iter().next()})).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+struct SyncHistoryQuery {
+    limit: Option<i64>,
+    cursor: Option<String>,
+}
+
+const SYNC_HISTORY_PAGE_SIZE: i64 = 20;
+
+async fn sync_history(State(state): State<Arc<AppState>>, Query(q): Query<SyncHistoryQuery>) -> impl IntoResponse {
+    let limit = q.limit.unwrap_or(SYNC_HISTORY_PAGE_SIZE).clamp(1, db::MAX_PAGE_SIZE);
+    let offset = match q.cursor.as_deref().map(str::parse::<i64>) {
+        Some(Ok(offset)) if offset >= 0 => offset,
+        Some(_) => return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "invalid cursor"}))).into_response(),
+        None => 0,
+    };
+
+    with_db(&state, move |conn| {
+        let result = db::count_sync_logs(conn).and_then(|total| Ok((total, db::get_sync_logs(conn, limit, offset)?)));
+        match result {
+            Ok((total, runs)) => {
+                let next = offset + runs.len() as i64;
+                Json(serde_json::json!({
+                    "runs": runs,
+                    "total": total,
+                    "next_cursor": (next < total && !runs.is_empty()).then(|| next.to_string()),
+                }))
+                .into_response()
             }
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response(),
         }
-    });
-
-    Json(serde_json::json!({"status": "sync started"}))
+    })
+    .await
 }
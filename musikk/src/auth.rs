@@ -0,0 +1,127 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{Html, IntoResponse, Json, Redirect, Response},
+    Form,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::api::AppState;
+
+const COOKIE_NAME: &str = "musikk_token";
+
+#[derive(Debug, Clone, Default)]
+pub struct AuthConfig {
+    // shared secret for the api, sent as a bearer token or set as a cookie by /login.
+    // None leaves the api open
+    pub api_token: Option<String>,
+    // refuse every mutating request, only browsing stays available
+    pub read_only: bool,
+    // origins allowed to call the api cross-origin, empty means same-origin only
+    pub cors_origins: Vec<String>,
+}
+
+impl AuthConfig {
+    pub fn cors_layer(&self) -> CorsLayer {
+        let origins: Vec<HeaderValue> = self
+            .cors_origins
+            .iter()
+            .filter_map(|o| HeaderValue::from_str(o).ok())
+            .collect();
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(origins))
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+            .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+            .allow_credentials(true)
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        let expected = match &self.api_token {
+            Some(t) => t,
+            None => return true,
+        };
+
+        let bearer = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        let cookie = headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(';'))
+            .filter_map(|c| c.trim().strip_prefix(&format!("{}=", COOKIE_NAME)).map(|s| s.to_string()))
+            .next();
+
+        bearer.map(|t| constant_time_eq(t, expected)).unwrap_or(false)
+            || cookie.map(|t| constant_time_eq(&t, expected)).unwrap_or(false)
+    }
+}
+
+// middleware for endpoints that control playback or start syncs
+pub async fn require_auth(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if state.auth.read_only && req.method() != Method::GET {
+        return (StatusCode::FORBIDDEN, Json(serde_json::json!({"error": "server is read-only"}))).into_response();
+    }
+    if !state.auth.is_authorized(req.headers()) {
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "unauthorized"}))).into_response();
+    }
+    next.run(req).await
+}
+
+pub async fn login_page() -> impl IntoResponse {
+    Html(login_html(None))
+}
+
+#[derive(Deserialize)]
+pub struct LoginForm {
+    token: String,
+}
+
+pub async fn login(State(state): State<Arc<AppState>>, Form(form): Form<LoginForm>) -> Response {
+    let valid = state
+        .auth
+        .api_token
+        .as_ref()
+        .map(|t| constant_time_eq(&form.token, t))
+        .unwrap_or(true);
+    if !valid {
+        return (StatusCode::UNAUTHORIZED, Html(login_html(Some("wrong token")))).into_response();
+    }
+
+    let cookie = format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age=31536000",
+        COOKIE_NAME, form.token
+    );
+    ([(header::SET_COOKIE, cookie)], Redirect::to("/")).into_response()
+}
+
+pub async fn logout() -> Response {
+    let cookie = format!("{}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0", COOKIE_NAME);
+    ([(header::SET_COOKIE, cookie)], Redirect::to("/login")).into_response()
+}
+
+fn login_html(error: Option<&str>) -> String {
+    let error = error.map(|e| format!("<p>{}</p>", e)).unwrap_or_default();
+    format!(
+        "<h1>musikk</h1>{}<form method=\"post\" action=\"/login\">\
+         <input type=\"password\" name=\"token\" placeholder=\"api token\" autofocus>\
+         <button type=\"submit\">log in</button></form>",
+        error
+    )
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
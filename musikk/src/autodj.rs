@@ -0,0 +1,175 @@
+// keeps the spotify queue topped up with tracks that mix well into whatever is playing
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::{self, AppState};
+use crate::db::{self, Track, TrackFilter};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_DEPTH: usize = 3;
+const MAX_DEPTH: usize = 5;
+// tempo window around the track we're mixing out of, half/double time counts
+const TEMPO_TOLERANCE_PCT: f64 = 6.0;
+// how many of the closest matches to look through before giving up on a constraint
+const CANDIDATES: i64 = 200;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StartRequest {
+    // what to draw from, e.g. {"sources": ["liked"], "genres": ["house"]}
+    pub filter: TrackFilter,
+    // tracks to keep queued ahead of the current one
+    pub depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Session {
+    pub enabled: bool,
+    pub filter: TrackFilter,
+    pub depth: usize,
+    pub current: Option<String>,
+    // what we queued that hasn't started playing yet, oldest first
+    pub queued: Vec<String>,
+    // every track played or queued this session, never picked again
+    #[serde(skip)]
+    pub used: HashSet<String>,
+    pub played_count: usize,
+    pub last_error: Option<String>,
+    // bumped on every start/stop so a tick that raced with one throws its result away
+    #[serde(skip)]
+    generation: u64,
+}
+
+impl Session {
+    pub fn start(&mut self, req: StartRequest) {
+        *self = Session {
+            enabled: true,
+            filter: req.filter,
+            depth: req.depth.unwrap_or(DEFAULT_DEPTH).clamp(1, MAX_DEPTH),
+            generation: self.generation + 1,
+            ..Default::default()
+        };
+    }
+
+    pub fn stop(&mut self) {
+        self.enabled = false;
+        self.generation += 1;
+    }
+}
+
+// runs for the lifetime of the server, idle while auto-dj is off
+pub async fn run(state: Arc<AppState>) {
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        let session = state.autodj.lock().await.clone();
+        if !session.enabled {
+            continue;
+        }
+
+        let result = tick(&state, session.clone()).await;
+        let mut current = state.autodj.lock().await;
+        if current.generation != session.generation {
+            continue;
+        }
+        match result {
+            Ok(updated) => *current = Session { last_error: None, ..updated },
+            Err(e) => {
+                eprintln!("auto-dj: {}", e);
+                current.last_error = Some(e);
+            }
+        }
+    }
+}
+
+async fn tick(state: &AppState, mut session: Session) -> Result<Session, String> {
+    let spotify = api::get_spotify_client(state).await?;
+    let playing = match spotify.get_playback_state().await? {
+        Some(p) if p.is_playing => p.item.and_then(|i| i.id),
+        _ => None,
+    };
+    let current = match playing {
+        Some(id) => id,
+        None => return Ok(session),
+    };
+
+    if session.current.as_deref() != Some(current.as_str()) {
+        session.played_count += 1;
+        session.current = Some(current.clone());
+        // everything we queued up to this one has played (or was skipped)
+        if let Some(pos) = session.queued.iter().position(|id| *id == current) {
+            session.queued.drain(..=pos);
+        }
+    }
+    session.used.insert(current.clone());
+
+    while session.queued.len() < session.depth {
+        // mix out of the last thing in the queue, or the current track
+        let from_id = session.queued.last().unwrap_or(&current).clone();
+        let snapshot = session.clone();
+        let next = state
+            .db
+            .run(move |conn| {
+                let from = db::get_track(conn, &from_id)?;
+                pick_next(conn, &snapshot, from.as_ref())
+            })
+            .await?
+            .map_err(|e| e.to_string())?;
+        let next = match next {
+            Some(t) => t,
+            None => return Err("no unplayed tracks left that match".to_string()),
+        };
+
+        spotify.queue_track(&next.spotify_id).await?;
+        println!("auto-dj: queued {}", next.name);
+        session.used.insert(next.spotify_id.clone());
+        session.queued.push(next.spotify_id);
+    }
+    Ok(session)
+}
+
+// closest in tempo within the session filter, harmonically compatible if anything is,
+// relaxing the key and then the tempo window when the library runs dry
+fn pick_next(conn: &Connection, session: &Session, from: Option<&Track>) -> rusqlite::Result<Option<Track>> {
+    let base = TrackFilter {
+        limit: Some(CANDIDATES),
+        offset: None,
+        ..session.filter.clone()
+    };
+    let key_modes = from
+        .and_then(|t| t.camelot())
+        .map(|c| c.compatible().iter().map(|c| c.key_mode()).collect::<Vec<_>>());
+    let tempo = from.and_then(|t| t.tempo);
+
+    let mut attempts = vec![];
+    if key_modes.is_some() || tempo.is_some() {
+        attempts.push(TrackFilter {
+            tempo,
+            tempo_tolerance_pct: Some(TEMPO_TOLERANCE_PCT),
+            tempo_octave: true,
+            key_modes: key_modes.clone(),
+            ..base.clone()
+        });
+    }
+    if tempo.is_some() {
+        attempts.push(TrackFilter {
+            tempo,
+            tempo_tolerance_pct: Some(TEMPO_TOLERANCE_PCT),
+            tempo_octave: true,
+            ..base.clone()
+        });
+    }
+    attempts.push(base);
+
+    for filter in attempts {
+        let tracks = db::query_tracks(conn, &filter)?;
+        if let Some(t) = tracks.into_iter().find(|t| !session.used.contains(&t.spotify_id)) {
+            return Ok(Some(t));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,66 @@
+// camelot wheel notation for spotify's pitch class (0 = C .. 11 = B) and mode (1 = major, 0 = minor).
+// majors are the B ring, minors the A ring, neighbours on the wheel mix well
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Camelot {
+    pub number: i64, // 1..=12
+    pub major: bool,
+}
+
+impl Camelot {
+    pub fn from_key(key: i64, mode: i64) -> Option<Self> {
+        if !(0..12).contains(&key) || !(0..=1).contains(&mode) {
+            return None;
+        }
+        let major = mode == 1;
+        // a minor key sits on the same number as its relative major, three semitones up
+        let major_key = if major { key } else { (key + 3) % 12 };
+        // each step around the wheel is a fifth (7 semitones), C major is 8B
+        let number = (major_key * 7 + 7) % 12 + 1;
+        Some(Self { number, major })
+    }
+
+    // "8A", "12b" etc
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let letter = s.chars().last()?;
+        let number: i64 = s[..s.len() - letter.len_utf8()].parse().ok()?;
+        if !(1..=12).contains(&number) {
+            return None;
+        }
+        let major = match letter {
+            'A' | 'a' => false,
+            'B' | 'b' => true,
+            _ => return None,
+        };
+        Some(Self { number, major })
+    }
+
+    // back to (key, mode)
+    pub fn key_mode(&self) -> (i64, i64) {
+        // inverse of from_key, 7 is its own inverse mod 12
+        let major_key = ((self.number - 8).rem_euclid(12) * 7) % 12;
+        if self.major {
+            (major_key, 1)
+        } else {
+            ((major_key + 9) % 12, 0)
+        }
+    }
+
+    // same key, relative major/minor and one step either way on the same ring
+    pub fn compatible(&self) -> Vec<Camelot> {
+        let step = |n: i64| (n - 1).rem_euclid(12) + 1;
+        vec![
+            *self,
+            Camelot { number: self.number, major: !self.major },
+            Camelot { number: step(self.number - 1), major: self.major },
+            Camelot { number: step(self.number + 1), major: self.major },
+        ]
+    }
+}
+
+impl std::fmt::Display for Camelot {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.number, if self.major { "B" } else { "A" })
+    }
+}
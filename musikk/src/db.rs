@@ -2,8 +2,12 @@
 
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+use crate::camelot::Camelot;
+use crate::migrate;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Track {
     pub spotify_id: String,
@@ -28,15 +32,103 @@ pub struct Track {
     pub liveness: Option<f64>,
     pub loudness: Option<f64>,
     pub unavailable: bool,
+    pub unavailable_reason: Option<String>,
+    pub unavailable_since: Option<String>,
     pub first_seen: Option<String>,
     pub last_seen: Option<String>,
     pub updated: Option<String>,
 }
 
+impl Track {
+    pub fn camelot(&self) -> Option<Camelot> {
+        Camelot::from_key(self.key?, self.mode?)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Playlist {
+    pub id: String,
+    pub name: String,
+    pub snapshot_id: Option<String>,
+    pub owner: Option<String>,
+    pub description: Option<String>,
+    pub track_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaylistRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistEntry {
+    pub spotify_id: String,
+    pub position: i64,
+    pub added_at: Option<String>,
+}
+
+// where a track is in getting audio features from reccobeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureStatus {
+    Pending,
+    Ok,
+    NotFound,
+    Error,
+}
+
+impl FeatureStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeatureStatus::Pending => "pending",
+            FeatureStatus::Ok => "ok",
+            FeatureStatus::NotFound => "not_found",
+            FeatureStatus::Error => "error",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "ok" => FeatureStatus::Ok,
+            "not_found" => FeatureStatus::NotFound,
+            "error" => FeatureStatus::Error,
+            _ => FeatureStatus::Pending,
+        }
+    }
+
+    // how long to wait before asking reccobeats again after `attempts` tries.
+    // errors are usually transient, not_found backs off from a week up to three months
+    pub fn retry_after(&self, attempts: i64) -> Option<chrono::Duration> {
+        match self {
+            FeatureStatus::Pending => Some(chrono::Duration::zero()),
+            FeatureStatus::Ok => None,
+            FeatureStatus::Error => Some(chrono::Duration::hours(12)),
+            FeatureStatus::NotFound => {
+                let weeks = 1i64 << (attempts - 1).clamp(0, 4);
+                Some(chrono::Duration::weeks(weeks).min(chrono::Duration::days(90)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SmartPlaylist {
+    pub id: i64,
+    pub name: String,
+    pub filter: TrackFilter,
+    // None until the first publish creates it
+    pub spotify_playlist_id: Option<String>,
+    pub track_count: Option<i64>,
+    pub last_published: Option<String>,
+    pub last_error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct Stats {
     pub total_tracks: i64,
     pub tracks_with_features: i64,
+    pub features_not_found: i64,
+    pub features_error: i64,
     pub unavailable_tracks: i64,
     pub last_sync: Option<String>,
     pub avg_tempo: Option<f64>,
@@ -54,12 +146,67 @@ pub struct SyncLog {
     pub error: Option<String>,
 }
 
-pub fn open_db(path: &Path) -> rusqlite::Result<Connection> {
+// a connection with the pragmas every writer should have, without touching the schema
+pub fn connect(path: &Path) -> rusqlite::Result<Connection> {
     let conn = Connection::open(path)?;
-    conn.execute_batch(include_str!("../schema.sql"))?;
+    // readers don't wait for a sync that's writing, and a crash can't leave half a transaction.
+    // wal sticks to the file, the rest is per connection
+    conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+    // wal makes NORMAL safe against corruption, only the last commits can be lost on power loss
+    conn.pragma_update(None, "synchronous", "NORMAL")?;
+    conn.pragma_update(None, "temp_store", "MEMORY")?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+    // room for every statement the sync save phase runs per track
+    conn.set_prepared_statement_cache_capacity(32);
+    Ok(conn)
+}
+
+// connects and brings the schema up to date
+pub fn open_db(path: &Path) -> rusqlite::Result<Connection> {
+    let mut conn = connect(path)?;
+    migrate::migrate(&mut conn)?;
+
+    // databases from before the search index, or one that got out of step
+    let tracks: i64 = conn.query_row("SELECT COUNT(*) FROM tracks", [], |row| row.get(0))?;
+    let indexed: i64 = conn.query_row("SELECT COUNT(*) FROM tracks_fts", [], |row| row.get(0))?;
+    if tracks != indexed {
+        rebuild_search_index(&conn)?;
+    }
     Ok(conn)
 }
 
+fn track_from_row(row: &rusqlite::Row) -> rusqlite::Result<Track> {
+    Ok(Track {
+        spotify_id: row.get("spotify_id")?,
+        recco_id: row.get("recco_id")?,
+        name: row.get("name")?,
+        artists: row.get("artists")?,
+        album_id: row.get("album_id")?,
+        album_name: row.get("album_name")?,
+        duration_ms: row.get("duration_ms")?,
+        popularity: row.get("popularity")?,
+        sources: row.get("sources")?,
+        genres: row.get("genres")?,
+        tempo: row.get("tempo")?,
+        key: row.get("key")?,
+        mode: row.get("mode")?,
+        danceability: row.get("danceability")?,
+        energy: row.get("energy")?,
+        valence: row.get("valence")?,
+        acousticness: row.get("acousticness")?,
+        instrumentalness: row.get("instrumentalness")?,
+        speechiness: row.get("speechiness")?,
+        liveness: row.get("liveness")?,
+        loudness: row.get("loudness")?,
+        unavailable: row.get::<_, i64>("unavailable")? == 1,
+        unavailable_reason: row.get("unavailable_reason")?,
+        unavailable_since: row.get("unavailable_since")?,
+        first_seen: row.get("first_seen")?,
+        last_seen: row.get("last_seen")?,
+        updated: row.get("updated")?,
+    })
+}
+
 pub fn get_config(conn: &Connection, key: &str) -> rusqlite::Result<Option<String>> {
     conn.query_row("SELECT value FROM config WHERE key = ?", [key], |row| {
         row.get(0)
@@ -76,40 +223,9 @@ pub fn set_config(conn: &Connection, key: &str, value: &str) -> rusqlite::Result
 }
 
 pub fn get_track(conn: &Connection, spotify_id: &str) -> rusqlite::Result<Option<Track>> {
-    conn.query_row(
-        "SELECT * FROM tracks WHERE spotify_id = ?",
-        [spotify_id],
-        |row| {
-            Ok(Track {
-                spotify_id: row.get("spotify_id")?,
-                recco_id: row.get("recco_id")?,
-                name: row.get("name")?,
-                artists: row.get("artists")?,
-                album_id: row.get("album_id")?,
-                album_name: row.get("album_name")?,
-                duration_ms: row.get("duration_ms")?,
-                popularity: row.get("popularity")?,
-                sources: row.get("sources")?,
-                genres: row.get("genres")?,
-                tempo: row.get("tempo")?,
-                key: row.get("key")?,
-                mode: row.get("mode")?,
-                danceability: row.get("danceability")?,
-                energy: row.get("energy")?,
-                valence: row.get("valence")?,
-                acousticness: row.get("acousticness")?,
-                instrumentalness: row.get("instrumentalness")?,
-                speechiness: row.get("speechiness")?,
-                liveness: row.get("liveness")?,
-                loudness: row.get("loudness")?,
-                unavailable: row.get::<_, i64>("unavailable")? == 1,
-                first_seen: row.get("first_seen")?,
-                last_seen: row.get("last_seen")?,
-                updated: row.get("updated")?,
-            })
-        },
-    )
-    .optional()
+    conn.prepare_cached("SELECT * FROM tracks WHERE spotify_id = ?")?
+        .query_row([spotify_id], track_from_row)
+        .optional()
 }
 
 pub fn upsert_track(conn: &Connection, track: &Track) -> rusqlite::Result<bool> {
@@ -117,7 +233,7 @@ pub fn upsert_track(conn: &Connection, track: &Track) -> rusqlite::Result<bool>
     let now = chrono::Utc::now().to_rfc3339();
 
     if existing.is_some() {
-        conn.execute(
+        conn.prepare_cached(
             "UPDATE tracks SET
                 recco_id = COALESCE(?, recco_id),
                 name = ?,
@@ -140,144 +256,335 @@ pub fn upsert_track(conn: &Connection, track: &Track) -> rusqlite::Result<bool>
                 liveness = COALESCE(?, liveness),
                 loudness = COALESCE(?, loudness),
                 unavailable = ?,
+                unavailable_reason = ?,
+                unavailable_since = CASE WHEN ? = 1 THEN COALESCE(unavailable_since, ?) ELSE NULL END,
                 last_seen = ?,
                 updated = ?
             WHERE spotify_id = ?",
-            params![
-                track.recco_id,
-                track.name,
-                track.artists,
-                track.album_id,
-                track.album_name,
-                track.duration_ms,
-                track.popularity,
-                track.sources,
-                track.genres,
-                track.tempo,
-                track.key,
-                track.mode,
-                track.danceability,
-                track.energy,
-                track.valence,
-                track.acousticness,
-                track.instrumentalness,
-                track.speechiness,
-                track.liveness,
-                track.loudness,
-                if track.unavailable { 1 } else { 0 },
-                now,
-                now,
-                track.spotify_id,
-            ],
-        )?;
+        )?
+        .execute(params![
+            track.recco_id,
+            track.name,
+            track.artists,
+            track.album_id,
+            track.album_name,
+            track.duration_ms,
+            track.popularity,
+            track.sources,
+            track.genres,
+            track.tempo,
+            track.key,
+            track.mode,
+            track.danceability,
+            track.energy,
+            track.valence,
+            track.acousticness,
+            track.instrumentalness,
+            track.speechiness,
+            track.liveness,
+            track.loudness,
+            if track.unavailable { 1 } else { 0 },
+            track.unavailable_reason,
+            if track.unavailable { 1 } else { 0 },
+            now,
+            now,
+            now,
+            track.spotify_id,
+        ])?;
+        index_track_search(conn, &track.spotify_id)?;
         Ok(false)
     } else {
-        conn.execute(
+        conn.prepare_cached(
             "INSERT INTO tracks (
                 spotify_id, recco_id, name, artists, album_id, album_name,
                 duration_ms, popularity, sources, genres, tempo, key, mode,
                 danceability, energy, valence, acousticness, instrumentalness,
-                speechiness, liveness, loudness, unavailable, first_seen, last_seen, updated
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-            params![
-                track.spotify_id,
-                track.recco_id,
-                track.name,
-                track.artists,
-                track.album_id,
-                track.album_name,
-                track.duration_ms,
-                track.popularity,
-                track.sources,
-                track.genres,
-                track.tempo,
-                track.key,
-                track.mode,
-                track.danceability,
-                track.energy,
-                track.valence,
-                track.acousticness,
-                track.instrumentalness,
-                track.speechiness,
-                track.liveness,
-                track.loudness,
-                if track.unavailable { 1 } else { 0 },
-                now,
-                now,
-                now,
-            ],
-        )?;
+                speechiness, liveness, loudness, unavailable, unavailable_reason, unavailable_since,
+                first_seen, last_seen, updated
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )?
+        .execute(params![
+            track.spotify_id,
+            track.recco_id,
+            track.name,
+            track.artists,
+            track.album_id,
+            track.album_name,
+            track.duration_ms,
+            track.popularity,
+            track.sources,
+            track.genres,
+            track.tempo,
+            track.key,
+            track.mode,
+            track.danceability,
+            track.energy,
+            track.valence,
+            track.acousticness,
+            track.instrumentalness,
+            track.speechiness,
+            track.liveness,
+            track.loudness,
+            if track.unavailable { 1 } else { 0 },
+            track.unavailable_reason,
+            if track.unavailable { Some(&now) } else { None },
+            now,
+            now,
+            now,
+        ])?;
+        index_track_search(conn, &track.spotify_id)?;
         Ok(true)
     }
 }
 
-#[derive(Default)]
+// lowercases and folds letters unicode61 won't strip diacritics from, so "bjørn" finds "Bjorn" and back.
+// used for both indexed text and queries
+pub fn fold_search_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        match c {
+            'ø' => out.push('o'),
+            'æ' => out.push_str("ae"),
+            'œ' => out.push_str("oe"),
+            'ß' => out.push_str("ss"),
+            'đ' | 'ð' => out.push('d'),
+            'ł' => out.push('l'),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// json arrays of artists/genres as space separated words
+fn json_list_text(json: Option<&str>) -> String {
+    json.and_then(|j| serde_json::from_str::<Vec<String>>(j).ok())
+        .map(|v| v.join(" "))
+        .unwrap_or_default()
+}
+
+// writes the search index row for a track from what's stored in tracks,
+// after the COALESCEs in upsert_track have been applied
+fn index_track_search(conn: &Connection, spotify_id: &str) -> rusqlite::Result<()> {
+    let (rowid, name, artists, album_name, genres) = conn
+        .prepare_cached("SELECT rowid, name, artists, album_name, genres FROM tracks WHERE spotify_id = ?")?
+        .query_row([spotify_id], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<String>>(4)?,
+            ))
+        })?;
+    conn.prepare_cached("DELETE FROM tracks_fts WHERE rowid = ?")?.execute([rowid])?;
+    conn.prepare_cached("INSERT INTO tracks_fts (rowid, name, artists, album_name, genres) VALUES (?, ?, ?, ?, ?)")?
+        .execute(params![
+            rowid,
+            fold_search_text(&name),
+            fold_search_text(&json_list_text(artists.as_deref())),
+            fold_search_text(album_name.as_deref().unwrap_or("")),
+            fold_search_text(&json_list_text(genres.as_deref())),
+        ])?;
+    Ok(())
+}
+
+pub fn rebuild_search_index(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM tracks_fts", [])?;
+    let mut stmt = conn.prepare_cached("SELECT spotify_id FROM tracks")?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for id in ids {
+        index_track_search(conn, &id)?;
+    }
+    Ok(())
+}
+
+// turns free text into an fts5 query: every word has to match, as a prefix, in any column
+fn search_match_query(s: &str) -> Option<String> {
+    let folded = fold_search_text(s);
+    let terms: Vec<String> = folded
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| format!("\"{}\"*", t))
+        .collect();
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+// bm25 weights for name, artists, album_name, genres
+const SEARCH_RANK: &str = "bm25(tracks_fts, 10.0, 5.0, 2.0, 1.0)";
+
+const DEFAULT_TEMPO_TOLERANCE_PCT: f64 = 4.0;
+pub const MAX_PAGE_SIZE: i64 = 1000;
+
+// numeric track columns that can be filtered by range
+pub const RANGE_COLUMNS: &[&str] = &[
+    "tempo",
+    "energy",
+    "danceability",
+    "valence",
+    "acousticness",
+    "instrumentalness",
+    "speechiness",
+    "liveness",
+    "loudness",
+    "duration_ms",
+    "popularity",
+    "key",
+    "mode",
+];
+
+// columns /api/tracks can sort by, text ones default to ascending, the rest descending
+const SORT_COLUMNS: &[(&str, bool)] = &[
+    ("name", false),
+    ("artists", false),
+    ("album_name", false),
+    ("tempo", true),
+    ("key", true),
+    ("mode", true),
+    ("energy", true),
+    ("danceability", true),
+    ("valence", true),
+    ("acousticness", true),
+    ("instrumentalness", true),
+    ("speechiness", true),
+    ("liveness", true),
+    ("loudness", true),
+    ("duration_ms", true),
+    ("popularity", true),
+    ("first_seen", true),
+    ("last_seen", true),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+    pub column: &'static str,
+    pub desc: bool,
+}
+
+// "key:asc,tempo:desc" or just "tempo"
+pub fn parse_sort(s: &str) -> Result<Vec<SortKey>, String> {
+    let mut keys = vec![];
+    for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, dir) = match part.split_once(':') {
+            Some((n, d)) => (n.trim(), Some(d.trim())),
+            None => (part, None),
+        };
+        let (column, default_desc) = SORT_COLUMNS
+            .iter()
+            .find(|(c, _)| *c == name)
+            .copied()
+            .ok_or_else(|| format!("can't sort by '{}'", name))?;
+        let desc = match dir {
+            None => default_desc,
+            Some("asc") => false,
+            Some("desc") => true,
+            Some(d) => return Err(format!("sort direction must be asc or desc, got '{}'", d)),
+        };
+        keys.push(SortKey { column, desc });
+    }
+    if keys.is_empty() {
+        return Err("empty sort".to_string());
+    }
+    Ok(keys)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureRange {
+    pub column: String,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TrackFilter {
-    pub tempo_min: Option<f64>,
-    pub tempo_max: Option<f64>,
-    pub energy_min: Option<f64>,
-    pub energy_max: Option<f64>,
-    pub danceability_min: Option<f64>,
-    pub danceability_max: Option<f64>,
-    pub valence_min: Option<f64>,
-    pub valence_max: Option<f64>,
+    // min/max on any of RANGE_COLUMNS
+    pub ranges: Vec<FeatureRange>,
+    // match around a target bpm instead of a literal range
+    pub tempo: Option<f64>,
+    pub tempo_tolerance_pct: Option<f64>,
+    // also match double and half time
+    pub tempo_octave: bool,
     pub key: Option<i64>,
+    pub mode: Option<i64>,
+    // (key, mode) pairs, used for harmonic matching
+    pub key_modes: Option<Vec<(i64, i64)>>,
     pub search: Option<String>,
     pub sources: Option<Vec<String>>,
+    pub playlists: Option<Vec<String>>,
     pub genres: Option<Vec<String>>,
     pub sort: Option<String>,
     pub limit: Option<i64>,
+    pub offset: Option<i64>,
 }
 
-pub fn query_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result<Vec<Track>> {
-    let mut sql = "SELECT * FROM tracks WHERE unavailable = 0".to_string();
-    let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
-
-    if let Some(v) = filter.tempo_min {
-        sql.push_str(" AND tempo >= ?");
-        params.push(Box::new(v));
+impl TrackFilter {
+    pub fn page_size(&self) -> i64 {
+        self.limit.unwrap_or(100).clamp(1, MAX_PAGE_SIZE)
     }
-    if let Some(v) = filter.tempo_max {
-        sql.push_str(" AND tempo <= ?");
-        params.push(Box::new(v));
-    }
-    if let Some(v) = filter.energy_min {
-        sql.push_str(" AND energy >= ?");
-        params.push(Box::new(v));
-    }
-    if let Some(v) = filter.energy_max {
-        sql.push_str(" AND energy <= ?");
-        params.push(Box::new(v));
-    }
-    if let Some(v) = filter.danceability_min {
-        sql.push_str(" AND danceability >= ?");
-        params.push(Box::new(v));
+}
+
+type SqlParams = Vec<Box<dyn rusqlite::ToSql>>;
+
+// WHERE clause shared by query_tracks and count_tracks
+fn filter_sql(filter: &TrackFilter) -> (String, SqlParams) {
+    let mut sql = " WHERE unavailable = 0".to_string();
+    let mut params: SqlParams = vec![];
+
+    for range in &filter.ranges {
+        // column names go into the sql, only ever use whitelisted ones
+        let column = match RANGE_COLUMNS.iter().find(|c| **c == range.column) {
+            Some(c) => c,
+            None => continue,
+        };
+        if let Some(v) = range.min {
+            sql.push_str(&format!(" AND {} >= ?", column));
+            params.push(Box::new(v));
+        }
+        if let Some(v) = range.max {
+            sql.push_str(&format!(" AND {} <= ?", column));
+            params.push(Box::new(v));
+        }
     }
-    if let Some(v) = filter.danceability_max {
-        sql.push_str(" AND danceability <= ?");
-        params.push(Box::new(v));
+    if let Some(target) = filter.tempo {
+        let tolerance = filter.tempo_tolerance_pct.unwrap_or(DEFAULT_TEMPO_TOLERANCE_PCT) / 100.0;
+        let targets: &[f64] = if filter.tempo_octave { &[1.0, 2.0, 0.5] } else { &[1.0] };
+        let ranges: Vec<&str> = targets.iter().map(|_| "tempo BETWEEN ? AND ?").collect();
+        sql.push_str(&format!(" AND ({})", ranges.join(" OR ")));
+        for factor in targets {
+            let t = target * factor;
+            params.push(Box::new(t * (1.0 - tolerance)));
+            params.push(Box::new(t * (1.0 + tolerance)));
+        }
     }
-    if let Some(v) = filter.valence_min {
-        sql.push_str(" AND valence >= ?");
+    if let Some(v) = filter.key {
+        sql.push_str(" AND key = ?");
         params.push(Box::new(v));
     }
-    if let Some(v) = filter.valence_max {
-        sql.push_str(" AND valence <= ?");
+    if let Some(v) = filter.mode {
+        sql.push_str(" AND mode = ?");
         params.push(Box::new(v));
     }
-    if let Some(v) = filter.key {
-        sql.push_str(" AND key = ?");
-        params.push(Box::new(v));
+    if let Some(ref key_modes) = filter.key_modes {
+        let placeholders: Vec<&str> = key_modes.iter().map(|_| "(?, ?)").collect();
+        sql.push_str(&format!(" AND (key, mode) IN (VALUES {})", placeholders.join(",")));
+        for (key, mode) in key_modes {
+            params.push(Box::new(*key));
+            params.push(Box::new(*mode));
+        }
     }
-    if let Some(ref s) = filter.search {
-        sql.push_str(" AND (name LIKE ? OR artists LIKE ?)");
-        let pattern = format!("%{}%", s);
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern));
+    if let Some(query) = filter.search.as_deref().and_then(search_match_query) {
+        sql.push_str(" AND tracks.rowid IN (SELECT rowid FROM tracks_fts WHERE tracks_fts MATCH ?)");
+        params.push(Box::new(query));
     }
     if let Some(ref sources) = filter.sources {
         if !sources.is_empty() {
-            // use json_each to check if any source matches
+            // "liked" and "album:<id>", playlists are filtered by id through `playlists`
             let placeholders: Vec<String> = sources.iter().map(|_| "?".to_string()).collect();
             sql.push_str(&format!(
                 " AND EXISTS (SELECT 1 FROM json_each(sources) WHERE value IN ({}))",
@@ -288,6 +595,18 @@ pub fn query_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result
             }
         }
     }
+    if let Some(ref playlists) = filter.playlists {
+        if !playlists.is_empty() {
+            let placeholders: Vec<String> = playlists.iter().map(|_| "?".to_string()).collect();
+            sql.push_str(&format!(
+                " AND EXISTS (SELECT 1 FROM playlist_tracks pt WHERE pt.spotify_id = tracks.spotify_id AND pt.playlist_id IN ({}))",
+                placeholders.join(",")
+            ));
+            for p in playlists {
+                params.push(Box::new(p.clone()));
+            }
+        }
+    }
     if let Some(ref genres) = filter.genres {
         if !genres.is_empty() {
             // use json_each to check if any genre matches
@@ -302,57 +621,79 @@ pub fn query_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result
         }
     }
 
-    let sort_col = match filter.sort.as_deref() {
-        Some("tempo") => "tempo",
-        Some("energy") => "energy",
-        Some("danceability") => "danceability",
-        Some("valence") => "valence",
-        Some("name") => "name",
-        Some("popularity") => "popularity",
-        _ => "name",
-    };
-    sql.push_str(&format!(" ORDER BY {} DESC", sort_col));
+    (sql, params)
+}
+
+pub fn count_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result<i64> {
+    let (where_sql, params) = filter_sql(filter);
+    let params_ref: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    conn.prepare_cached(&format!("SELECT COUNT(*) FROM tracks{}", where_sql))?
+        .query_row(params_ref.as_slice(), |row| row.get(0))
+}
+
+pub fn query_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result<Vec<Track>> {
+    let (where_sql, mut params) = filter_sql(filter);
+    let mut sql = format!("SELECT * FROM tracks{}", where_sql);
+    let search = filter.search.as_deref().and_then(search_match_query);
+
+    match filter.tempo {
+        // closest to the target first, measured relative to the matched tempo octave
+        Some(target) if filter.sort.is_none() => {
+            if filter.tempo_octave {
+                sql.push_str(" ORDER BY MIN(ABS(tempo - ?), ABS(tempo / 2.0 - ?), ABS(tempo * 2.0 - ?))");
+                params.extend([Box::new(target) as Box<dyn rusqlite::ToSql>, Box::new(target), Box::new(target)]);
+            } else {
+                sql.push_str(" ORDER BY ABS(tempo - ?)");
+                params.push(Box::new(target));
+            }
+        }
+        // best matches first when searching without an explicit sort
+        None if filter.sort.is_none() && search.is_some() => {
+            sql.push_str(&format!(
+                " ORDER BY (SELECT {} FROM tracks_fts WHERE tracks_fts MATCH ? AND tracks_fts.rowid = tracks.rowid)",
+                SEARCH_RANK
+            ));
+            params.push(Box::new(search));
+        }
+        _ => {
+            // invalid sorts are rejected by the api, fall back to name here
+            let keys = parse_sort(filter.sort.as_deref().unwrap_or("name")).unwrap_or_else(|_| parse_sort("name").unwrap());
+            let order: Vec<String> = keys
+                .iter()
+                .map(|k| format!("{} {} NULLS LAST", k.column, if k.desc { "DESC" } else { "ASC" }))
+                .collect();
+            sql.push_str(&format!(" ORDER BY {}", order.join(", ")));
+        }
+    }
+    // stable order so pages don't overlap
+    sql.push_str(", spotify_id");
 
-    let limit = filter.limit.unwrap_or(100).min(1000);
-    sql.push_str(&format!(" LIMIT {}", limit));
+    // the cap is per page, use offset to get the rest. bound rather than inlined so every page
+    // of the same query reuses one cached statement
+    sql.push_str(" LIMIT ? OFFSET ?");
+    params.push(Box::new(filter.page_size()));
+    params.push(Box::new(filter.offset.unwrap_or(0).max(0)));
 
     let params_ref: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
-    let mut stmt = conn.prepare(&sql)?;
+    let mut stmt = conn.prepare_cached(&sql)?;
     let tracks = stmt
-        .query_map(params_ref.as_slice(), |row| {
-            Ok(Track {
-                spotify_id: row.get("spotify_id")?,
-                recco_id: row.get("recco_id")?,
-                name: row.get("name")?,
-                artists: row.get("artists")?,
-                album_id: row.get("album_id")?,
-                album_name: row.get("album_name")?,
-                duration_ms: row.get("duration_ms")?,
-                popularity: row.get("popularity")?,
-                sources: row.get("sources")?,
-                genres: row.get("genres")?,
-                tempo: row.get("tempo")?,
-                key: row.get("key")?,
-                mode: row.get("mode")?,
-                danceability: row.get("danceability")?,
-                energy: row.get("energy")?,
-                valence: row.get("valence")?,
-                acousticness: row.get("acousticness")?,
-                instrumentalness: row.get("instrumentalness")?,
-                speechiness: row.get("speechiness")?,
-                liveness: row.get("liveness")?,
-                loudness: row.get("loudness")?,
-                unavailable: row.get::<_, i64>("unavailable")? == 1,
-                first_seen: row.get("first_seen")?,
-                last_seen: row.get("last_seen")?,
-                updated: row.get("updated")?,
-            })
-        })?
+        .query_map(params_ref.as_slice(), track_from_row)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(tracks)
 }
 
+// every match, no ordering or paging, for callers that rank tracks themselves
+pub fn query_all_tracks(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result<Vec<Track>> {
+    let (where_sql, params) = filter_sql(filter);
+    let params_ref: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let mut stmt = conn.prepare_cached(&format!("SELECT * FROM tracks{}", where_sql))?;
+    let tracks = stmt
+        .query_map(params_ref.as_slice(), track_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tracks)
+}
+
 pub fn get_stats(conn: &Connection) -> rusqlite::Result<Stats> {
     let total_tracks: i64 = conn.query_row("SELECT COUNT(*) FROM tracks", [], |row| row.get(0))?;
 
@@ -362,6 +703,18 @@ pub fn get_stats(conn: &Connection) -> rusqlite::Result<Stats> {
         |row| row.get(0),
     )?;
 
+    let features_not_found: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM tracks WHERE tempo IS NULL AND feature_status = 'not_found'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    let features_error: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM tracks WHERE tempo IS NULL AND feature_status = 'error'",
+        [],
+        |row| row.get(0),
+    )?;
+
     let unavailable_tracks: i64 = conn.query_row(
         "SELECT COUNT(*) FROM tracks WHERE unavailable = 1",
         [],
@@ -395,6 +748,8 @@ pub fn get_stats(conn: &Connection) -> rusqlite::Result<Stats> {
     Ok(Stats {
         total_tracks,
         tracks_with_features,
+        features_not_found,
+        features_error,
         unavailable_tracks,
         last_sync,
         avg_tempo,
@@ -427,49 +782,347 @@ pub fn finish_sync_log(
     Ok(())
 }
 
+fn sync_log_from_row(row: &rusqlite::Row) -> rusqlite::Result<SyncLog> {
+    Ok(SyncLog {
+        id: row.get("id")?,
+        started_at: row.get("started_at")?,
+        finished_at: row.get("finished_at")?,
+        tracks_added: row.get("tracks_added")?,
+        tracks_updated: row.get("tracks_updated")?,
+        tracks_unavailable: row.get("tracks_unavailable")?,
+        error: row.get("error")?,
+    })
+}
+
+// newest first
+pub fn get_sync_logs(conn: &Connection, limit: i64, offset: i64) -> rusqlite::Result<Vec<SyncLog>> {
+    let mut stmt = conn.prepare_cached("SELECT * FROM sync_log ORDER BY id DESC LIMIT ? OFFSET ?")?;
+    let logs = stmt
+        .query_map(params![limit, offset], sync_log_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(logs)
+}
+
+pub fn count_sync_logs(conn: &Connection) -> rusqlite::Result<i64> {
+    conn.query_row("SELECT COUNT(*) FROM sync_log", [], |row| row.get(0))
+}
+
+// tracks without features that are due for another reccobeats lookup
 pub fn get_tracks_missing_features(conn: &Connection) -> rusqlite::Result<Vec<String>> {
-    let mut stmt =
-        conn.prepare("SELECT spotify_id FROM tracks WHERE tempo IS NULL AND unavailable = 0")?;
+    let mut stmt = conn.prepare_cached(
+        "SELECT spotify_id, feature_status, feature_attempted_at, feature_attempts
+         FROM tracks WHERE tempo IS NULL AND unavailable = 0",
+    )?;
+    let now = chrono::Utc::now();
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<i64>>(3)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ids = rows
+        .into_iter()
+        .filter(|(_, status, attempted_at, attempts)| {
+            let status = FeatureStatus::parse(status.as_deref().unwrap_or("pending"));
+            let wait = match status.retry_after(attempts.unwrap_or(0)) {
+                Some(wait) => wait,
+                None => return false,
+            };
+            match attempted_at.as_deref().and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok()) {
+                Some(t) => now.signed_duration_since(t) >= wait,
+                None => true,
+            }
+        })
+        .map(|(id, ..)| id)
+        .collect();
+    Ok(ids)
+}
+
+pub fn set_feature_status(conn: &Connection, spotify_id: &str, status: FeatureStatus) -> rusqlite::Result<()> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.prepare_cached(
+        "UPDATE tracks SET
+            feature_status = ?,
+            feature_attempted_at = ?,
+            feature_attempts = CASE WHEN ? = 'ok' THEN 0 ELSE COALESCE(feature_attempts, 0) + 1 END
+         WHERE spotify_id = ?",
+    )?
+    .execute(params![status.as_str(), now, status.as_str(), spotify_id])?;
+    Ok(())
+}
+
+pub fn get_available_track_ids(conn: &Connection) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare_cached("SELECT spotify_id FROM tracks WHERE unavailable = 0")?;
     let ids = stmt
         .query_map([], |row| row.get(0))?
         .collect::<Result<Vec<String>, _>>()?;
     Ok(ids)
 }
 
+// flags a track as unavailable, returns true if it was available before
+pub fn mark_unavailable(conn: &Connection, spotify_id: &str, reason: &str) -> rusqlite::Result<bool> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let changed = conn
+        .prepare_cached(
+            "UPDATE tracks SET unavailable = 1, unavailable_reason = ?, unavailable_since = ?, updated = ?
+             WHERE spotify_id = ? AND unavailable = 0",
+        )?
+        .execute(params![reason, now, now, spotify_id])?;
+    Ok(changed > 0)
+}
+
+// tracks listed under a source in the sources json, minus ones already flagged as removed
+pub fn get_source_track_ids(conn: &Connection, source: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT spotify_id FROM tracks, json_each(tracks.sources)
+         WHERE json_each.value = ? AND COALESCE(unavailable_reason, '') != 'removed'",
+    )?;
+    let ids = stmt
+        .query_map([source], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+    Ok(ids)
+}
+
+// takes one source out of a track's sources json
+pub fn remove_source(conn: &Connection, spotify_id: &str, source: &str) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "UPDATE tracks SET sources = (SELECT json_group_array(value) FROM json_each(tracks.sources) WHERE value != ?)
+         WHERE spotify_id = ?",
+    )?
+    .execute(params![source, spotify_id])?;
+    Ok(())
+}
+
+// non-playlist sources, playlists come from get_playlists
 pub fn get_all_sources(conn: &Connection) -> rusqlite::Result<Vec<String>> {
-    let mut stmt = conn.prepare("SELECT sources FROM tracks WHERE sources IS NOT NULL")?;
-    let mut all_sources: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let has_liked: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM tracks, json_each(tracks.sources) WHERE json_each.value = 'liked')",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(if has_liked { vec!["liked".to_string()] } else { vec![] })
+}
 
-    let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+// playlists each of the given tracks is in, by name
+pub fn get_track_playlists(conn: &Connection, spotify_ids: &[String]) -> rusqlite::Result<HashMap<String, Vec<PlaylistRef>>> {
+    let mut result: HashMap<String, Vec<PlaylistRef>> = HashMap::new();
+    if spotify_ids.is_empty() {
+        return Ok(result);
+    }
+    let placeholders: Vec<&str> = spotify_ids.iter().map(|_| "?").collect();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT DISTINCT pt.spotify_id, p.id, p.name FROM playlist_tracks pt JOIN playlists p ON p.id = pt.playlist_id
+         WHERE pt.spotify_id IN ({}) ORDER BY p.name",
+        placeholders.join(",")
+    ))?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(spotify_ids), |row| {
+        Ok((row.get::<_, String>(0)?, PlaylistRef { id: row.get(1)?, name: row.get(2)? }))
+    })?;
     for row in rows {
-        if let Ok(sources_json) = row {
-            if let Ok(sources) = serde_json::from_str::<Vec<String>>(&sources_json) {
-                for s in sources {
-                    // skip album sources
-                    if !s.starts_with("album:") {
-                        all_sources.insert(s);
-                    }
-                }
-            }
-        }
+        let (spotify_id, playlist) = row?;
+        result.entry(spotify_id).or_default().push(playlist);
     }
-
-    let mut result: Vec<String> = all_sources.into_iter().collect();
-    result.sort();
     Ok(result)
 }
 
+pub fn upsert_playlist(conn: &Connection, playlist: &Playlist) -> rusqlite::Result<()> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO playlists (id, name, snapshot_id, owner, description, updated)
+         VALUES (?, ?, ?, ?, ?, ?)
+         ON CONFLICT(id) DO UPDATE SET
+            name = excluded.name,
+            snapshot_id = excluded.snapshot_id,
+            owner = excluded.owner,
+            description = excluded.description,
+            updated = excluded.updated",
+        params![
+            playlist.id,
+            playlist.name,
+            playlist.snapshot_id,
+            playlist.owner,
+            playlist.description,
+            now,
+        ],
+    )?;
+    Ok(())
+}
+
+// replaces the full membership of a playlist
+pub fn set_playlist_tracks(conn: &Connection, playlist_id: &str, entries: &[PlaylistEntry]) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM playlist_tracks WHERE playlist_id = ?", [playlist_id])?;
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO playlist_tracks (playlist_id, spotify_id, position, added_at) VALUES (?, ?, ?, ?)",
+    )?;
+    for e in entries {
+        stmt.execute(params![playlist_id, e.spotify_id, e.position, e.added_at])?;
+    }
+    Ok(())
+}
+
+pub fn get_playlist_tracks(conn: &Connection, playlist_id: &str) -> rusqlite::Result<Vec<PlaylistEntry>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT spotify_id, position, added_at FROM playlist_tracks WHERE playlist_id = ? ORDER BY position",
+    )?;
+    let entries = stmt
+        .query_map([playlist_id], |row| {
+            Ok(PlaylistEntry {
+                spotify_id: row.get(0)?,
+                position: row.get(1)?,
+                added_at: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(entries)
+}
+
+pub fn delete_playlist(conn: &Connection, playlist_id: &str) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM playlist_tracks WHERE playlist_id = ?", [playlist_id])?;
+    conn.execute("DELETE FROM playlists WHERE id = ?", [playlist_id])?;
+    Ok(())
+}
+
+pub fn get_playlists(conn: &Connection) -> rusqlite::Result<Vec<Playlist>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT p.id, p.name, p.snapshot_id, p.owner, p.description,
+                (SELECT COUNT(*) FROM playlist_tracks pt WHERE pt.playlist_id = p.id) AS track_count
+         FROM playlists p ORDER BY p.name",
+    )?;
+    let playlists = stmt
+        .query_map([], |row| {
+            Ok(Playlist {
+                id: row.get("id")?,
+                name: row.get("name")?,
+                snapshot_id: row.get("snapshot_id")?,
+                owner: row.get("owner")?,
+                description: row.get("description")?,
+                track_count: row.get("track_count")?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(playlists)
+}
+
+fn smart_playlist_from_row(row: &rusqlite::Row) -> rusqlite::Result<SmartPlaylist> {
+    let filter: String = row.get("filter")?;
+    Ok(SmartPlaylist {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        // a filter we can't read any more matches everything rather than failing every sync
+        filter: serde_json::from_str(&filter).unwrap_or_default(),
+        spotify_playlist_id: row.get("spotify_playlist_id")?,
+        track_count: row.get("track_count")?,
+        last_published: row.get("last_published")?,
+        last_error: row.get("last_error")?,
+    })
+}
+
+pub fn get_smart_playlists(conn: &Connection) -> rusqlite::Result<Vec<SmartPlaylist>> {
+    let mut stmt = conn.prepare_cached("SELECT * FROM smart_playlists ORDER BY name, id")?;
+    let playlists = stmt
+        .query_map([], smart_playlist_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(playlists)
+}
+
+pub fn get_smart_playlist(conn: &Connection, id: i64) -> rusqlite::Result<Option<SmartPlaylist>> {
+    conn.query_row("SELECT * FROM smart_playlists WHERE id = ?", [id], smart_playlist_from_row)
+        .optional()
+}
+
+pub fn insert_smart_playlist(
+    conn: &Connection,
+    name: &str,
+    filter: &TrackFilter,
+    spotify_playlist_id: Option<&str>,
+) -> rusqlite::Result<i64> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO smart_playlists (name, filter, spotify_playlist_id, created, updated) VALUES (?, ?, ?, ?, ?)",
+        params![name, serde_json::to_string(filter).unwrap(), spotify_playlist_id, now, now],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+// returns false if there's no smart playlist with that id
+pub fn update_smart_playlist(
+    conn: &Connection,
+    id: i64,
+    name: &str,
+    filter: &TrackFilter,
+    spotify_playlist_id: Option<&str>,
+) -> rusqlite::Result<bool> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let changed = conn.execute(
+        "UPDATE smart_playlists SET name = ?, filter = ?, spotify_playlist_id = ?, updated = ? WHERE id = ?",
+        params![name, serde_json::to_string(filter).unwrap(), spotify_playlist_id, now, id],
+    )?;
+    Ok(changed > 0)
+}
+
+pub fn delete_smart_playlist(conn: &Connection, id: i64) -> rusqlite::Result<bool> {
+    let changed = conn.execute("DELETE FROM smart_playlists WHERE id = ?", [id])?;
+    Ok(changed > 0)
+}
+
+// right after creating its spotify playlist, before anything else can fail
+pub fn set_smart_playlist_spotify_id(conn: &Connection, id: i64, spotify_playlist_id: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE smart_playlists SET spotify_playlist_id = ? WHERE id = ?",
+        params![spotify_playlist_id, id],
+    )?;
+    Ok(())
+}
+
+// outcome of publishing to spotify, the playlist id is kept on error so we retry the same one
+pub fn set_smart_playlist_published(
+    conn: &Connection,
+    id: i64,
+    spotify_playlist_id: Option<&str>,
+    track_count: Option<i64>,
+    error: Option<&str>,
+) -> rusqlite::Result<()> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "UPDATE smart_playlists SET
+            spotify_playlist_id = COALESCE(?, spotify_playlist_id),
+            track_count = COALESCE(?, track_count),
+            last_published = CASE WHEN ? IS NULL THEN ? ELSE last_published END,
+            last_error = ?
+         WHERE id = ?",
+        params![spotify_playlist_id, track_count, error, now, error, id],
+    )?;
+    Ok(())
+}
+
+pub fn get_created_playlist_ids(conn: &Connection) -> rusqlite::Result<HashSet<String>> {
+    let mut stmt = conn.prepare_cached("SELECT id FROM created_playlists")?;
+    let ids = stmt.query_map([], |row| row.get(0))?.collect::<Result<HashSet<String>, _>>()?;
+    Ok(ids)
+}
+
+pub fn add_created_playlist(conn: &Connection, id: &str, name: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO created_playlists (id, name, created) VALUES (?, ?, ?)",
+        params![id, name, chrono::Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
 pub fn get_all_genres(conn: &Connection) -> rusqlite::Result<Vec<String>> {
-    let mut stmt = conn.prepare("SELECT genres FROM tracks WHERE genres IS NOT NULL")?;
+    let mut stmt = conn.prepare_cached("SELECT genres FROM tracks WHERE genres IS NOT NULL")?;
     let mut all_genres: std::collections::HashSet<String> = std::collections::HashSet::new();
 
     let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
-    for row in rows {
-        if let Ok(genres_json) = row {
-            if let Ok(genres) = serde_json::from_str::<Vec<String>>(&genres_json) {
-                for g in genres {
-                    all_genres.insert(g);
-                }
+    for genres_json in rows.flatten() {
+        if let Ok(genres) = serde_json::from_str::<Vec<String>>(&genres_json) {
+            for g in genres {
+                all_genres.insert(g);
             }
         }
     }
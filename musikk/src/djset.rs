@@ -0,0 +1,317 @@
+// builds dj sets that follow a tempo/energy curve over time with smooth transitions
+
+use crate::camelot::Camelot;
+use crate::db::Track;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+// how much each kind of miss counts when picking the next track
+const TEMPO_WEIGHT: f64 = 5.0; // per fraction off the target bpm, 4% off costs 0.2
+const ENERGY_WEIGHT: f64 = 1.0;
+const KEY_CLASH_PENALTY: f64 = 0.5;
+const BPM_JUMP_PENALTY: f64 = 1.0;
+
+const DEFAULT_MAX_BPM_STEP_PCT: f64 = 6.0;
+pub const MAX_BEAM_WIDTH: usize = 16;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurvePoint {
+    // position in the set, 0 = start, 1 = end
+    pub at: f64,
+    pub tempo: f64,
+    pub energy: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetOptions {
+    pub duration_min: f64,
+    // empty uses default_curve()
+    pub curve: Vec<CurvePoint>,
+    // require consecutive tracks to be harmonically compatible where possible
+    pub harmonic: bool,
+    // bpm change between consecutive tracks before it costs extra
+    pub max_bpm_step_pct: f64,
+    pub no_artist_repeat: bool,
+    // optional first track
+    pub start: Option<String>,
+    // 1 is plain greedy, more keeps that many candidate sets and picks the best at the end
+    pub beam_width: usize,
+}
+
+impl Default for SetOptions {
+    fn default() -> Self {
+        Self {
+            duration_min: 60.0,
+            curve: vec![],
+            harmonic: true,
+            max_bpm_step_pct: DEFAULT_MAX_BPM_STEP_PCT,
+            no_artist_repeat: true,
+            start: None,
+            beam_width: 1,
+        }
+    }
+}
+
+// warm up around 100 bpm, peak two thirds in, cool down at the end
+fn default_curve() -> Vec<CurvePoint> {
+    vec![
+        CurvePoint { at: 0.0, tempo: 100.0, energy: 0.4 },
+        CurvePoint { at: 0.65, tempo: 128.0, energy: 0.9 },
+        CurvePoint { at: 1.0, tempo: 110.0, energy: 0.5 },
+    ]
+}
+
+#[derive(Debug, Serialize)]
+pub struct Transition {
+    pub from: String,
+    pub to: String,
+    pub bpm_delta: f64,
+    pub bpm_delta_pct: f64,
+    pub energy_delta: f64,
+    pub from_key: Option<String>,
+    pub to_key: Option<String>,
+    pub key_compatible: bool,
+}
+
+#[derive(Debug)]
+pub struct SetEntry {
+    pub track: Track,
+    pub start_ms: i64,
+    pub target_tempo: f64,
+    pub target_energy: f64,
+}
+
+#[derive(Debug)]
+pub struct DjSet {
+    pub tracks: Vec<SetEntry>,
+    pub transitions: Vec<Transition>,
+    pub duration_ms: i64,
+}
+
+impl SetOptions {
+    pub fn validate(&mut self) -> Result<(), String> {
+        if !(1.0..=600.0).contains(&self.duration_min) {
+            return Err("duration_min must be between 1 and 600".to_string());
+        }
+        if self.curve.is_empty() {
+            self.curve = default_curve();
+        }
+        for p in &self.curve {
+            if !(0.0..=1.0).contains(&p.at) {
+                return Err(format!("curve point at {} is outside 0..1", p.at));
+            }
+            if p.tempo <= 0.0 || !(0.0..=1.0).contains(&p.energy) {
+                return Err(format!("curve point at {} needs tempo > 0 and energy in 0..1", p.at));
+            }
+        }
+        self.curve.sort_by(|a, b| a.at.total_cmp(&b.at));
+        if self.max_bpm_step_pct <= 0.0 {
+            return Err("max_bpm_step_pct must be above 0".to_string());
+        }
+        self.beam_width = self.beam_width.clamp(1, MAX_BEAM_WIDTH);
+        Ok(())
+    }
+
+    // target (tempo, energy) at a position, linear between control points
+    fn target_at(&self, pos: f64) -> (f64, f64) {
+        let first = &self.curve[0];
+        let last = &self.curve[self.curve.len() - 1];
+        if pos <= first.at {
+            return (first.tempo, first.energy);
+        }
+        if pos >= last.at {
+            return (last.tempo, last.energy);
+        }
+        for pair in self.curve.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            if pos <= b.at {
+                let t = if b.at > a.at { (pos - a.at) / (b.at - a.at) } else { 1.0 };
+                return (a.tempo + (b.tempo - a.tempo) * t, a.energy + (b.energy - a.energy) * t);
+            }
+        }
+        (last.tempo, last.energy)
+    }
+}
+
+// a track with what sequencing needs already pulled out
+struct Candidate {
+    tempo: f64,
+    energy: f64,
+    duration_ms: i64,
+    camelot: Option<Camelot>,
+    artists: Vec<String>,
+}
+
+impl Candidate {
+    fn from_track(t: &Track) -> Option<Self> {
+        Some(Self {
+            // transition costs divide by tempo, a 0 would turn them into inf/NaN
+            tempo: t.tempo.filter(|v| v.is_finite() && *v > 0.0)?,
+            energy: t.energy?,
+            duration_ms: t.duration_ms.filter(|d| *d > 0)?,
+            camelot: t.camelot(),
+            artists: t
+                .artists
+                .as_deref()
+                .and_then(|a| serde_json::from_str(a).ok())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Partial {
+    seq: Vec<usize>,
+    targets: Vec<(f64, f64)>,
+    artists: HashSet<String>,
+    elapsed_ms: i64,
+    cost: f64,
+}
+
+impl Partial {
+    fn mean_cost(&self) -> f64 {
+        self.cost / self.seq.len().max(1) as f64
+    }
+}
+
+fn transition_cost(opts: &SetOptions, prev: &Candidate, next: &Candidate) -> f64 {
+    let mut cost = 0.0;
+    if opts.harmonic {
+        let compatible = match (prev.camelot, next.camelot) {
+            (Some(a), Some(b)) => a.compatible().contains(&b),
+            _ => false,
+        };
+        if !compatible {
+            cost += KEY_CLASH_PENALTY;
+        }
+    }
+    let step_pct = (next.tempo - prev.tempo).abs() / prev.tempo * 100.0;
+    if step_pct > opts.max_bpm_step_pct {
+        cost += BPM_JUMP_PENALTY + (step_pct - opts.max_bpm_step_pct) / 100.0 * TEMPO_WEIGHT;
+    }
+    cost
+}
+
+// sequences tracks from `pool` to fill the duration. candidates without a positive tempo, energy
+// or duration are skipped. with beam_width 1 each step takes the cheapest next track,
+// wider beams keep the cheapest few partial sets around and finish with the best one
+pub fn build_set(pool: Vec<Track>, opts: &SetOptions) -> Result<DjSet, String> {
+    let (tracks, candidates): (Vec<Track>, Vec<Candidate>) = pool
+        .into_iter()
+        .filter_map(|t| Candidate::from_track(&t).map(|c| (t, c)))
+        .unzip();
+    if candidates.is_empty() {
+        return Err("no tracks with tempo, energy and duration match the constraints".to_string());
+    }
+    let target_ms = (opts.duration_min * 60_000.0) as i64;
+
+    let mut start = Partial { seq: vec![], targets: vec![], artists: HashSet::new(), elapsed_ms: 0, cost: 0.0 };
+    if let Some(ref id) = opts.start {
+        let i = tracks
+            .iter()
+            .position(|t| &t.spotify_id == id)
+            .ok_or_else(|| format!("start track {} isn't among the matching tracks", id))?;
+        start.seq.push(i);
+        start.targets.push(opts.target_at(0.0));
+        start.artists.extend(candidates[i].artists.iter().cloned());
+        start.elapsed_ms = candidates[i].duration_ms;
+    }
+
+    let mut beam = vec![start];
+    let mut finished: Vec<Partial> = vec![];
+    while !beam.is_empty() {
+        let mut next_beam: Vec<Partial> = vec![];
+        for partial in &beam {
+            let pos = partial.elapsed_ms as f64 / target_ms as f64;
+            let (tempo, energy) = opts.target_at(pos);
+            let prev = partial.seq.last().map(|i| &candidates[*i]);
+
+            let mut scored: Vec<(usize, f64)> = candidates
+                .iter()
+                .enumerate()
+                .filter(|(i, c)| {
+                    let artist_repeat = opts.no_artist_repeat && c.artists.iter().any(|a| partial.artists.contains(a));
+                    !partial.seq.contains(i) && !artist_repeat
+                })
+                .map(|(i, c)| {
+                    let mut cost = (c.tempo - tempo).abs() / tempo * TEMPO_WEIGHT + (c.energy - energy).abs() * ENERGY_WEIGHT;
+                    if let Some(prev) = prev {
+                        cost += transition_cost(opts, prev, c);
+                    }
+                    (i, cost)
+                })
+                .collect();
+            if scored.is_empty() {
+                // ran out of tracks, keep what we have
+                finished.push(partial.clone());
+                continue;
+            }
+            scored.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+
+            for (i, cost) in scored.into_iter().take(opts.beam_width) {
+                let mut p = partial.clone();
+                p.seq.push(i);
+                p.targets.push((tempo, energy));
+                p.artists.extend(candidates[i].artists.iter().cloned());
+                p.elapsed_ms += candidates[i].duration_ms;
+                p.cost += cost;
+                if p.elapsed_ms >= target_ms {
+                    finished.push(p);
+                } else {
+                    next_beam.push(p);
+                }
+            }
+        }
+        next_beam.sort_by(|a, b| a.cost.total_cmp(&b.cost));
+        next_beam.truncate(opts.beam_width);
+        beam = next_beam;
+    }
+
+    let best = finished
+        .into_iter()
+        .min_by(|a, b| {
+            // a set that fills the time beats one that ran out of tracks
+            let full = |p: &Partial| p.elapsed_ms >= target_ms;
+            full(b).cmp(&full(a)).then(a.mean_cost().total_cmp(&b.mean_cost()))
+        })
+        .ok_or("couldn't build a set")?;
+
+    let transitions = best
+        .seq
+        .windows(2)
+        .map(|pair| {
+            let (a, b) = (&candidates[pair[0]], &candidates[pair[1]]);
+            Transition {
+                from: tracks[pair[0]].spotify_id.clone(),
+                to: tracks[pair[1]].spotify_id.clone(),
+                bpm_delta: b.tempo - a.tempo,
+                bpm_delta_pct: (b.tempo - a.tempo) / a.tempo * 100.0,
+                energy_delta: b.energy - a.energy,
+                from_key: a.camelot.map(|c| c.to_string()),
+                to_key: b.camelot.map(|c| c.to_string()),
+                key_compatible: matches!((a.camelot, b.camelot), (Some(x), Some(y)) if x.compatible().contains(&y)),
+            }
+        })
+        .collect();
+
+    let mut tracks: Vec<Option<Track>> = tracks.into_iter().map(Some).collect();
+    let mut start_ms = 0;
+    let entries = best
+        .seq
+        .iter()
+        .zip(&best.targets)
+        .map(|(i, (tempo, energy))| {
+            let entry = SetEntry {
+                track: tracks[*i].take().unwrap(),
+                start_ms,
+                target_tempo: *tempo,
+                target_energy: *energy,
+            };
+            start_ms += candidates[*i].duration_ms;
+            entry
+        })
+        .collect();
+
+    Ok(DjSet { tracks: entries, transitions, duration_ms: best.elapsed_ms })
+}
@@ -0,0 +1,204 @@
+// live updates pushed to the frontend over /api/events
+
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+use crate::api::{self, AppState};
+use crate::db::{self, Track};
+use crate::http::RetryLog;
+use crate::spotify::PlaybackTrack;
+use crate::sync::SyncStatus;
+
+// only poll spotify this often, and only while someone is listening
+const PLAYER_POLL_INTERVAL: Duration = Duration::from_secs(3);
+// slow subscribers skip events past this instead of holding everyone up
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    Player {
+        is_playing: bool,
+        progress_ms: Option<i64>,
+        item: Option<PlaybackTrack>,
+        // our copy of the track with audio features, if it's in the library
+        track: Option<Box<Track>>,
+        camelot: Option<String>,
+    },
+    Sync {
+        phase: &'static str,
+        message: String,
+        done: Option<usize>,
+        total: Option<usize>,
+        error: Option<String>,
+    },
+}
+
+impl Event {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::Player { .. } => "player",
+            Event::Sync { .. } => "sync",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<Event>,
+    // new subscribers get this straight away instead of waiting for the next change
+    last_player: Arc<Mutex<Option<Event>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { tx, last_player: Arc::new(Mutex::new(None)) }
+    }
+
+    pub fn send(&self, event: Event) {
+        if let Event::Player { .. } = event {
+            *self.last_player.lock().unwrap() = Some(event.clone());
+        }
+        // no subscribers isn't an error
+        let _ = self.tx.send(event);
+    }
+
+    pub fn subscribe(&self) -> (Option<Event>, broadcast::Receiver<Event>) {
+        let rx = self.tx.subscribe();
+        (self.last_player.lock().unwrap().clone(), rx)
+    }
+
+    fn has_subscribers(&self) -> bool {
+        self.tx.receiver_count() > 0
+    }
+}
+
+// sync progress, printed for the cli and sent to subscribers when running in the server
+#[derive(Debug, Clone)]
+pub struct SyncProgress {
+    bus: Option<EventBus>,
+    phase: Arc<Mutex<&'static str>>,
+    // kept up to date for GET /api/sync
+    status: Option<Arc<Mutex<SyncStatus>>>,
+}
+
+impl Default for SyncProgress {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl SyncProgress {
+    pub fn new(bus: Option<EventBus>) -> Self {
+        Self { bus, phase: Arc::new(Mutex::new("started")), status: None }
+    }
+
+    pub fn with_status(self, status: Arc<Mutex<SyncStatus>>) -> Self {
+        Self { status: Some(status), ..self }
+    }
+
+    pub fn set_log_id(&self, log_id: Option<i64>) {
+        if let Some(status) = &self.status {
+            status.lock().unwrap().log_id = log_id;
+        }
+    }
+
+    // start of a new phase of the sync
+    pub fn phase(&self, phase: &'static str, message: impl Into<String>) {
+        *self.phase.lock().unwrap() = phase;
+        self.emit(0, message.into(), None, None);
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.emit(1, message.into(), None, None);
+    }
+
+    pub fn count(&self, label: &str, done: usize, total: usize) {
+        self.emit(2, format!("{} {}/{}", label, done, total), Some((done, total)), None);
+    }
+
+    // request retries, shown with the rest of the sync output
+    pub fn retry_log(&self) -> RetryLog {
+        let progress = self.clone();
+        RetryLog::new(move |notice| progress.info(notice))
+    }
+
+    // something failed but the sync carries on
+    pub fn warn(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.emit(1, message.clone(), None, Some(message));
+    }
+
+    pub fn failed(&self, error: &str) {
+        *self.phase.lock().unwrap() = "failed";
+        self.emit(0, format!("sync failed: {}", error), None, Some(error.to_string()));
+    }
+
+    fn emit(&self, indent: usize, message: String, counts: Option<(usize, usize)>, error: Option<String>) {
+        println!("{}{}", "  ".repeat(indent), message);
+        let phase = *self.phase.lock().unwrap();
+        if let Some(status) = &self.status {
+            let mut status = status.lock().unwrap();
+            status.phase = Some(phase);
+            status.message = Some(message.clone());
+            // counts belong to the step that reported them
+            status.done = counts.map(|c| c.0);
+            status.total = counts.map(|c| c.1);
+            if error.is_some() {
+                status.errors += 1;
+            }
+        }
+        if let Some(bus) = &self.bus {
+            bus.send(Event::Sync {
+                phase,
+                message,
+                done: counts.map(|c| c.0),
+                total: counts.map(|c| c.1),
+                error,
+            });
+        }
+    }
+}
+
+// sends a player event whenever the track or play/pause state changes
+pub async fn watch_player(state: Arc<AppState>) {
+    let mut last: Option<(Option<String>, bool)> = None;
+    loop {
+        tokio::time::sleep(PLAYER_POLL_INTERVAL).await;
+        if !state.events.has_subscribers() {
+            // whoever connects next should get a fresh event
+            last = None;
+            continue;
+        }
+
+        let playback = match api::get_spotify_client(&state).await {
+            Ok(spotify) => match spotify.get_playback_state().await {
+                Ok(p) => p,
+                Err(_) => continue,
+            },
+            Err(_) => continue,
+        };
+        let item = playback.as_ref().and_then(|p| p.item.clone());
+        let is_playing = playback.as_ref().map(|p| p.is_playing).unwrap_or(false);
+        let key = (item.as_ref().and_then(|i| i.id.clone()), is_playing);
+        if last.as_ref() == Some(&key) {
+            continue;
+        }
+
+        let track = match key.0.clone() {
+            Some(id) => state.db.run(move |conn| db::get_track(conn, &id)).await.ok().and_then(|r| r.ok().flatten()),
+            None => None,
+        };
+        state.events.send(Event::Player {
+            is_playing,
+            progress_ms: playback.as_ref().and_then(|p| p.progress_ms),
+            item,
+            camelot: track.as_ref().and_then(|t| t.camelot()).map(|c| c.to_string()),
+            track: track.map(Box::new),
+        });
+        last = Some(key);
+    }
+}
@@ -0,0 +1,169 @@
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::{Mutex, Semaphore};
+
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF_MS: u64 = 500;
+const MAX_BACKOFF_MS: u64 = 30_000;
+// longer retry-afters mean we're banned for a while, give up instead of hanging
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(120);
+
+// where retry notices go instead of stderr, e.g. the progress output of a sync
+#[derive(Clone)]
+pub struct RetryLog(Arc<dyn Fn(String) + Send + Sync>);
+
+impl RetryLog {
+    pub fn new(f: impl Fn(String) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl std::fmt::Debug for RetryLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RetryLog")
+    }
+}
+
+// shared request layer for spotify and reccobeats: caps in-flight requests,
+// honors 429 retry-after for every caller and retries transient failures with backoff.
+// clones share the limits, so hand out clones rather than making a new client per task
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    name: &'static str,
+    client: Client,
+    permits: Arc<Semaphore>,
+    // set when the api answers 429, every request waits until then
+    blocked_until: Arc<Mutex<Option<Instant>>>,
+    // per clone, stderr when unset
+    retry_log: Option<RetryLog>,
+}
+
+impl HttpClient {
+    pub fn new(name: &'static str, max_in_flight: usize) -> Self {
+        Self {
+            name,
+            client: Client::new(),
+            permits: Arc::new(Semaphore::new(max_in_flight.max(1))),
+            blocked_until: Arc::new(Mutex::new(None)),
+            retry_log: None,
+        }
+    }
+
+    pub fn with_retry_log(self, log: RetryLog) -> Self {
+        Self { retry_log: Some(log), ..self }
+    }
+
+    pub fn get(&self, url: &str) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn put(&self, url: &str) -> RequestBuilder {
+        self.client.put(url)
+    }
+
+    pub fn delete(&self, url: &str) -> RequestBuilder {
+        self.client.delete(url)
+    }
+
+    // sends the request, retrying 429s and connect errors for everything, and 5xx and timeouts
+    // only for idempotent methods. a POST that got a 5xx or timed out may have been applied
+    // already (e.g. tracks added to a playlist), so repeating it could do it twice.
+    // returns the last response once retries run out so callers can report the error body
+    pub async fn send(&self, req: RequestBuilder) -> Result<Response, String> {
+        let _permit = self.permits.acquire().await.map_err(|e| e.to_string())?;
+        let mut attempt = 0;
+        let idempotent = req
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .is_some_and(|r| r.method().is_idempotent());
+
+        loop {
+            self.wait_if_blocked().await;
+
+            let this_try = req.try_clone().ok_or("request body can't be retried")?;
+            let (wait, reason) = match this_try.send().await {
+                Ok(resp) if resp.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    let wait = retry_after(&resp).unwrap_or_else(|| backoff(attempt));
+                    if attempt >= MAX_RETRIES || wait > MAX_RETRY_AFTER {
+                        return Ok(resp);
+                    }
+                    *self.blocked_until.lock().await = Some(Instant::now() + wait);
+                    (wait, "rate limited".to_string())
+                }
+                Ok(resp) if idempotent && is_transient(resp.status()) => {
+                    if attempt >= MAX_RETRIES {
+                        return Ok(resp);
+                    }
+                    (backoff(attempt), resp.status().to_string())
+                }
+                Ok(resp) => return Ok(resp),
+                Err(e) => {
+                    // a connect error means the request never reached the server
+                    let retryable = e.is_connect() || (idempotent && (e.is_timeout() || e.is_request()));
+                    if attempt >= MAX_RETRIES || !retryable {
+                        return Err(e.to_string());
+                    }
+                    (backoff(attempt), e.to_string())
+                }
+            };
+
+            attempt += 1;
+            let notice = format!(
+                "{}: {} - retry {}/{} in {:.1}s",
+                self.name,
+                reason,
+                attempt,
+                MAX_RETRIES,
+                wait.as_secs_f64()
+            );
+            match &self.retry_log {
+                Some(log) => (log.0)(notice),
+                None => eprintln!("  {}", notice),
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    async fn wait_if_blocked(&self) {
+        let until = *self.blocked_until.lock().await;
+        if let Some(until) = until {
+            let now = Instant::now();
+            if until > now {
+                tokio::time::sleep(until - now).await;
+            }
+        }
+    }
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 500 | 502 | 503 | 504)
+}
+
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let secs: u64 = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    // spotify sometimes sends 0, give it a moment anyway
+    Some(Duration::from_secs(secs.max(1)))
+}
+
+// exponential backoff with up to 50% jitter so parallel retries don't line up
+fn backoff(attempt: u32) -> Duration {
+    let base = (BASE_BACKOFF_MS << attempt.min(10)).min(MAX_BACKOFF_MS);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    let jitter = nanos % (base / 2 + 1);
+    Duration::from_millis(base + jitter)
+}
@@ -1,5 +1,16 @@
 mod api;
+mod auth;
+mod autodj;
+mod camelot;
 mod db;
+mod djset;
+mod events;
+mod http;
+mod migrate;
+mod playlist;
+mod pool;
+mod schedule;
+mod similar;
 mod spotify;
 mod sync;
 
@@ -24,15 +35,72 @@ enum Commands {
     Serve {
         #[arg(short, long, default_value = "1670")]
         port: u16,
+        // disable playback control and sync, only browsing
+        #[arg(long)]
+        read_only: bool,
+        // allow cross-origin requests from this origin, repeatable
+        #[arg(long = "cors-origin")]
+        cors_origins: Vec<String>,
+        // sync in the background this often, e.g. 6h or 30m
+        #[arg(long, conflicts_with = "sync_cron")]
+        sync_every: Option<String>,
+        // or on a cron schedule in local time, e.g. "0 4 * * *"
+        #[arg(long)]
+        sync_cron: Option<String>,
     },
     Sync {
         #[arg(long)]
         dry_run: bool,
         #[arg(long)]
         backfill: bool,
+        // refetch everything instead of only changed playlists and new liked songs
+        #[arg(long)]
+        full: bool,
+        // reccobeats requests in flight at once
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
     },
     Auth,
     Stats,
+    Playlist {
+        #[command(subcommand)]
+        command: PlaylistCommand,
+    },
+    Db {
+        #[command(subcommand)]
+        command: DbCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum PlaylistCommand {
+    // write every track matching a filter to a spotify playlist. a same-named playlist is only
+    // replaced if musikk created it, or with --replace; --playlist-id picks the playlist directly
+    Create {
+        #[arg(long, required_unless_present = "playlist_id")]
+        name: Option<String>,
+        // TrackFilter json, same as the `filter` field /api/tracks returns
+        #[arg(long = "from-filter")]
+        from_filter: String,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        public: bool,
+        // write to this playlist instead of the one called --name
+        #[arg(long = "playlist-id")]
+        playlist_id: Option<String>,
+        // overwrite a same-named playlist that wasn't created by musikk
+        #[arg(long)]
+        replace: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum DbCommand {
+    // apply pending schema migrations, every other command also does this on open
+    Migrate,
+    // show the schema version and any pending migrations
+    Version,
 }
 
 fn get_spotify_creds() -> (String, String) {
@@ -53,52 +121,49 @@ async fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Serve { port } => {
+        Commands::Serve { port, read_only, cors_origins, sync_every, sync_cron } => {
+            let sync_schedule = match (sync_every, sync_cron) {
+                (Some(every), _) => Some(schedule::Schedule::parse_interval(&every)),
+                (_, Some(cron)) => Some(schedule::Schedule::parse_cron(&cron)),
+                _ => None,
+            }
+            .transpose()
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            if read_only && sync_schedule.is_some() {
+                eprintln!("--read-only can't be combined with a sync schedule");
+                std::process::exit(1);
+            }
+
             let (client_id, client_secret) = get_spotify_creds();
-            let state = api::AppState {
-                db_path: cli.db,
-                spotify_client_id: client_id,
-                spotify_client_secret: client_secret,
+            let mut state = api::AppState::new(cli.db, client_id, client_secret);
+            state.auth = auth::AuthConfig {
+                api_token: std::env::var("MUSIKK_API_TOKEN").ok().filter(|t| !t.is_empty()),
+                read_only,
+                cors_origins,
             };
+            state.sync_schedule = sync_schedule;
             api::serve(state, port).await;
         }
 
-        Commands::Sync { dry_run, backfill } => {
+        Commands::Sync { dry_run, backfill, full, concurrency } => {
             let (client_id, client_secret) = get_spotify_creds();
 
             // ensure db exists
             let _ = db::open_db(&cli.db).expect("failed to open db");
 
-            let log_id = if !dry_run {
-                let conn = db::open_db(&cli.db).expect("failed to open db");
-                Some(db::start_sync_log(&conn).expect("failed to start sync log"))
-            } else {
-                None
-            };
-
-            let mut spotify = spotify::SpotifyClient::new(
+            let spotify = spotify::SpotifyClient::new(
                 client_id,
                 client_secret,
                 "http://127.0.0.1:1670/callback".to_string(),
             );
 
-            match sync::run_sync(&cli.db, &mut spotify, dry_run, backfill).await {
-                Ok(result) => {
-                    if let Some(id) = log_id {
-                        let conn = db::open_db(&cli.db).expect("failed to open db");
-                        db::finish_sync_log(&conn, id, result.added, result.updated, result.unavailable, None)
-                            .expect("failed to finish sync log");
-                    }
-                }
-                Err(e) => {
-                    eprintln!("sync failed: {}", e);
-                    if let Some(id) = log_id {
-                        let conn = db::open_db(&cli.db).expect("failed to open db");
-                        db::finish_sync_log(&conn, id, 0, 0, 0, Some(&e))
-                            .expect("failed to finish sync log");
-                    }
-                    std::process::exit(1);
-                }
+            let opts = sync::SyncOptions { dry_run, backfill, full, concurrency };
+            // failures are already printed by the progress output
+            if sync::run_logged_sync(&cli.db, &spotify, &opts, &events::SyncProgress::default()).await.is_err() {
+                std::process::exit(1);
             }
         }
 
@@ -152,6 +217,87 @@ async fn main() {
             println!("auth complete!");
         }
 
+        Commands::Playlist { command: PlaylistCommand::Create { name, from_filter, description, public, playlist_id, replace } } => {
+            let filter: db::TrackFilter = serde_json::from_str(&from_filter)
+                .unwrap_or_else(|e| {
+                    eprintln!("invalid filter: {}", e);
+                    std::process::exit(1);
+                });
+            if let Some(Err(e)) = filter.sort.as_deref().map(db::parse_sort) {
+                eprintln!("invalid filter: {}", e);
+                std::process::exit(1);
+            }
+
+            let (client_id, client_secret) = get_spotify_creds();
+            let conn = db::open_db(&cli.db).expect("failed to open db");
+            let refresh_token = db::get_config(&conn, "spotify_refresh_token")
+                .expect("failed to read config")
+                .expect("no refresh token - run 'musikk auth' first");
+
+            let mut spotify = spotify::SpotifyClient::new(
+                client_id,
+                client_secret,
+                "http://127.0.0.1:1670/callback".to_string(),
+            );
+            let token = spotify.refresh_token(&refresh_token).await
+                .expect("failed to refresh token");
+            if let Some(new_refresh) = token.refresh_token {
+                db::set_config(&conn, "spotify_refresh_token", &new_refresh)
+                    .expect("failed to save refresh token");
+            }
+
+            let target = playlist::PushTarget { name: name.unwrap_or_default(), description, public, playlist_id, replace };
+            let pool = pool::DbPool::new(cli.db.clone(), 1);
+            match playlist::push_filter(&pool, &spotify, &target, &filter).await {
+                Ok(result) => println!(
+                    "{} playlist '{}' ({}) with {} tracks",
+                    if result.created { "created" } else { "replaced" },
+                    result.name,
+                    result.playlist_id,
+                    result.tracks
+                ),
+                Err(e) => {
+                    eprintln!("playlist create failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Db { command: DbCommand::Migrate } => {
+            let mut conn = db::connect(&cli.db).expect("failed to open db");
+            let from = migrate::current_version(&conn).expect("failed to read schema version");
+            match migrate::migrate(&mut conn) {
+                Ok(applied) if applied.is_empty() => println!("schema is up to date (version {})", from),
+                Ok(applied) => {
+                    for m in &applied {
+                        println!("applied {:03} {}", m.version, m.name);
+                    }
+                    println!("migrated from version {} to {}", from, migrate::latest_version());
+                }
+                Err(e) => {
+                    eprintln!("migration failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Db { command: DbCommand::Version } => {
+            let conn = db::connect(&cli.db).expect("failed to open db");
+            let current = migrate::current_version(&conn).expect("failed to read schema version");
+            println!("schema version: {} (latest {})", current, migrate::latest_version());
+            match migrate::pending(&conn) {
+                Ok(pending) => {
+                    for m in pending {
+                        println!("pending {:03} {}", m.version, m.name);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::Stats => {
             let conn = db::open_db(&cli.db).expect("failed to open db");
             let stats = db::get_stats(&conn).expect("failed to get stats");
@@ -160,6 +306,8 @@ async fn main() {
             println!("------------");
             println!("total tracks:        {}", stats.total_tracks);
             println!("with audio features: {}", stats.tracks_with_features);
+            println!("not in reccobeats:   {}", stats.features_not_found);
+            println!("feature errors:      {}", stats.features_error);
             println!("unavailable:         {}", stats.unavailable_tracks);
             if let Some(tempo) = stats.avg_tempo {
                 println!("avg tempo:           {:.1} bpm", tempo);
@@ -0,0 +1,226 @@
+// versioned schema changes, tracked in PRAGMA user_version.
+// append new migrations to MIGRATIONS, never edit or reorder ones that have shipped
+
+use rusqlite::{Connection, TransactionBehavior};
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    up: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+pub static MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, name: "baseline", up: baseline },
+    Migration { version: 2, name: "playlist names out of sources", up: drop_playlist_sources },
+    Migration { version: 3, name: "created playlists", up: created_playlists },
+];
+
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+pub fn current_version(conn: &Connection) -> rusqlite::Result<i64> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+pub fn pending(conn: &Connection) -> rusqlite::Result<Vec<&'static Migration>> {
+    let current = check_version(conn)?;
+    Ok(MIGRATIONS.iter().filter(|m| m.version > current).collect())
+}
+
+// applies everything newer than the db, each migration in its own transaction together with
+// the version bump. returns what was applied
+pub fn migrate(conn: &mut Connection) -> rusqlite::Result<Vec<&'static Migration>> {
+    let mut applied = vec![];
+    for m in MIGRATIONS {
+        // immediate so a second process migrating at the same time waits and then sees our version
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        if check_version(&tx)? >= m.version {
+            continue;
+        }
+        (m.up)(&tx)?;
+        tx.pragma_update(None, "user_version", m.version)?;
+        tx.commit()?;
+        applied.push(m);
+    }
+    Ok(applied)
+}
+
+// an older binary could misread or clobber columns it doesn't know about
+fn check_version(conn: &Connection) -> rusqlite::Result<i64> {
+    let current = current_version(conn)?;
+    if current > latest_version() {
+        return Err(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_ERROR),
+            Some(format!(
+                "database is at schema version {} but this build only knows up to {}, upgrade musikk",
+                current,
+                latest_version()
+            )),
+        ));
+    }
+    Ok(current)
+}
+
+// everything from before migrations existed. databases from then are at user_version 0 with any
+// subset of this applied, so it only creates what's missing
+fn baseline(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(include_str!("../migrations/001_baseline.sql"))?;
+    // columns added after the first release, CREATE TABLE IF NOT EXISTS won't add them
+    add_column_if_missing(conn, "tracks", "unavailable_reason", "TEXT")?;
+    add_column_if_missing(conn, "tracks", "unavailable_since", "TEXT")?;
+    if add_column_if_missing(conn, "tracks", "feature_status", "TEXT DEFAULT 'pending'")? {
+        conn.execute("UPDATE tracks SET feature_status = 'ok' WHERE tempo IS NOT NULL", [])?;
+    }
+    add_column_if_missing(conn, "tracks", "feature_attempted_at", "TEXT")?;
+    add_column_if_missing(conn, "tracks", "feature_attempts", "INTEGER DEFAULT 0")?;
+    Ok(())
+}
+
+// old syncs put playlist names into tracks.sources next to liked/album:<id>, membership is in
+// playlist_tracks now and a stale name would keep matching the sources filter
+fn drop_playlist_sources(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE tracks SET sources = (
+             SELECT json_group_array(value) FROM json_each(tracks.sources)
+             WHERE value = 'liked' OR value LIKE 'album:%'
+         )
+         WHERE json_valid(sources)",
+        [],
+    )?;
+    Ok(())
+}
+
+// playlists `musikk playlist create` made, the only ones it replaces without being told to
+fn created_playlists(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS created_playlists (
+             id TEXT PRIMARY KEY,
+             name TEXT NOT NULL,
+             created TEXT NOT NULL
+         )",
+    )
+}
+
+// returns true if the column was added
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, decl: &str) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>("name"))?
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .any(|name| name == column);
+    if !exists {
+        conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, decl))?;
+    }
+    Ok(!exists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+
+    const FIRST_RELEASE: &str = include_str!("../tests/fixtures/v0.sql");
+
+    fn columns(conn: &Connection, table: &str) -> Vec<String> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table)).unwrap();
+        stmt.query_map([], |row| row.get("name")).unwrap().collect::<Result<_, _>>().unwrap()
+    }
+
+    fn feature_status(conn: &Connection, id: &str) -> String {
+        conn.query_row("SELECT feature_status FROM tracks WHERE spotify_id = ?", [id], |row| row.get(0))
+            .unwrap()
+    }
+
+    fn table_exists(conn: &Connection, name: &str) -> bool {
+        conn.query_row("SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE name = ?)", [name], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn migrates_first_release_db() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(FIRST_RELEASE).unwrap();
+        assert_eq!(current_version(&conn).unwrap(), 0);
+
+        let applied = migrate(&mut conn).unwrap();
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+
+        let cols = columns(&conn, "tracks");
+        for col in ["unavailable_reason", "unavailable_since", "feature_status", "feature_attempted_at", "feature_attempts"] {
+            assert!(cols.iter().any(|c| c == col), "missing column {}", col);
+        }
+        for table in ["playlists", "playlist_tracks", "smart_playlists", "tracks_fts", "created_playlists"] {
+            assert!(table_exists(&conn, table), "missing table {}", table);
+        }
+
+        // existing rows survive, and tracks that already had features aren't retried
+        let with = db::get_track(&conn, "4uLU6hMCjMI75M1A2tKUQC").unwrap().unwrap();
+        assert_eq!(with.tempo, Some(124.0));
+        assert_eq!(feature_status(&conn, "4uLU6hMCjMI75M1A2tKUQC"), "ok");
+        assert_eq!(feature_status(&conn, "0eGsygTp906u18L0Oimnem"), "pending");
+        assert_eq!(db::count_sync_logs(&conn).unwrap(), 1);
+        assert_eq!(db::get_config(&conn, "spotify_refresh_token").unwrap().as_deref(), Some("token"));
+        assert_eq!(with.sources.as_deref(), Some(r#"["liked","album:1lGMbRKOnGXvPzHN9nTxJD"]"#));
+    }
+
+    #[test]
+    fn open_db_migrates_and_indexes_old_file() {
+        let path = std::env::temp_dir().join(format!("musikk-migrate-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Connection::open(&path).unwrap().execute_batch(FIRST_RELEASE).unwrap();
+
+        let conn = db::open_db(&path).unwrap();
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+        let filter = db::TrackFilter { search: Some("bjorn".to_string()), ..Default::default() };
+        let found = db::query_tracks(&conn, &filter).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].spotify_id, "4uLU6hMCjMI75M1A2tKUQC");
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+
+    #[test]
+    fn fresh_db_then_nothing_pending() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert_eq!(pending(&conn).unwrap().len(), MIGRATIONS.len());
+        migrate(&mut conn).unwrap();
+        assert!(pending(&conn).unwrap().is_empty());
+        assert!(migrate(&mut conn).unwrap().is_empty());
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+    }
+
+    // what open_db built before migrations: the full schema, but user_version still 0
+    #[test]
+    fn baseline_on_pre_migration_schema() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        baseline(&conn).unwrap();
+        conn.execute("INSERT INTO tracks (spotify_id, name, tempo, feature_status) VALUES ('a', 'a', NULL, 'not_found')", [])
+            .unwrap();
+        assert_eq!(current_version(&conn).unwrap(), 0);
+
+        migrate(&mut conn).unwrap();
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+        assert_eq!(feature_status(&conn, "a"), "not_found");
+    }
+
+    #[test]
+    fn refuses_newer_db() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", latest_version() + 1).unwrap();
+        assert!(migrate(&mut conn).is_err());
+        assert!(pending(&conn).is_err());
+    }
+
+    #[test]
+    fn versions_are_ordered() {
+        for (i, m) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(m.version, i as i64 + 1, "migration {} is out of order", m.name);
+        }
+    }
+}
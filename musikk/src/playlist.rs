@@ -0,0 +1,227 @@
+use crate::db::{self, TrackFilter};
+use crate::events::SyncProgress;
+use crate::pool::{self, DbPool};
+use crate::spotify::SpotifyClient;
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+// spotify won't hold more than this in one playlist
+pub const MAX_PLAYLIST_TRACKS: usize = 10_000;
+
+// where push_filter writes to
+#[derive(Debug, Default)]
+pub struct PushTarget {
+    pub name: String,
+    pub description: Option<String>,
+    pub public: bool,
+    // replace this playlist instead of looking one up by name
+    pub playlist_id: Option<String>,
+    // also replace a same-named playlist that musikk didn't create
+    pub replace: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PushResult {
+    pub playlist_id: String,
+    pub name: String,
+    // false if an existing playlist was replaced
+    pub created: bool,
+    pub tracks: usize,
+}
+
+// every track the filter matches in its sort order, ignoring its limit and offset
+pub fn filter_track_ids(conn: &Connection, filter: &TrackFilter) -> rusqlite::Result<Vec<String>> {
+    let mut page = filter.clone();
+    page.limit = Some(db::MAX_PAGE_SIZE);
+    page.offset = Some(0);
+
+    let mut ids = vec![];
+    loop {
+        let tracks = db::query_tracks(conn, &page)?;
+        let done = (tracks.len() as i64) < db::MAX_PAGE_SIZE;
+        ids.extend(tracks.into_iter().map(|t| t.spotify_id));
+        if done || ids.len() >= MAX_PLAYLIST_TRACKS {
+            break;
+        }
+        page.offset = Some(ids.len() as i64);
+    }
+    ids.truncate(MAX_PLAYLIST_TRACKS);
+    Ok(ids)
+}
+
+// writes the filter result to a playlist of the user's. without an explicit id it goes to the
+// playlist called `target.name` that an earlier push created, or a new one. a same-named playlist
+// the user made themselves is only overwritten with `target.replace`
+pub async fn push_filter(
+    db: &DbPool,
+    spotify: &SpotifyClient,
+    target: &PushTarget,
+    filter: &TrackFilter,
+) -> Result<PushResult, String> {
+    let filter = filter.clone();
+    let (ids, created_ids) = db
+        .run(move |conn| {
+            let ids = filter_track_ids(conn, &filter)?;
+            Ok::<_, rusqlite::Error>((ids, db::get_created_playlist_ids(conn)?))
+        })
+        .await?
+        .map_err(|e| e.to_string())?;
+
+    let user_id = spotify.get_user_id().await?;
+    let owned: Vec<_> = spotify
+        .get_playlists()
+        .await?
+        .into_iter()
+        .filter(|p| p.owner.id == user_id)
+        .collect();
+
+    let existing = match &target.playlist_id {
+        Some(id) => Some(
+            owned
+                .iter()
+                .find(|p| &p.id == id)
+                .ok_or_else(|| format!("playlist {} isn't one of yours", id))?,
+        ),
+        None => {
+            let same_name: Vec<_> = owned.iter().filter(|p| p.name == target.name).collect();
+            match same_name.iter().find(|p| created_ids.contains(&p.id)) {
+                Some(p) => Some(*p),
+                None if target.replace || same_name.is_empty() => same_name.first().copied(),
+                None => {
+                    return Err(format!(
+                        "there's already a playlist called '{}' that musikk didn't create, pass its id or replace to overwrite it",
+                        target.name
+                    ))
+                }
+            }
+        }
+    };
+
+    let (playlist_id, name, created) = match existing {
+        Some(p) => (p.id.clone(), p.name.clone(), false),
+        None => {
+            let playlist = spotify
+                .create_playlist(&user_id, &target.name, target.description.as_deref(), target.public)
+                .await?;
+            let (id, name) = (playlist.id.clone(), target.name.clone());
+            db.run(move |conn| db::add_created_playlist(conn, &id, &name))
+                .await?
+                .map_err(|e| e.to_string())?;
+            (playlist.id, target.name.clone(), true)
+        }
+    };
+    spotify.replace_playlist_items(&playlist_id, &ids).await?;
+
+    Ok(PushResult {
+        playlist_id,
+        name,
+        created,
+        tracks: ids.len(),
+    })
+}
+
+// recomputes every smart playlist and brings its spotify playlist in line by removing tracks that
+// dropped out and appending new ones, so tracks that stay keep their added date.
+// failures are stored per playlist and reported as warnings, only not being able to read the
+// smart playlists at all is an error
+pub async fn publish_smart_playlists(
+    db_path: &Path,
+    spotify: &SpotifyClient,
+    user_id: &str,
+    owned_ids: &HashSet<String>,
+    progress: &SyncProgress,
+) -> Result<(), String> {
+    let smart = pool::run_blocking(db_path, |conn| db::get_smart_playlists(conn).map_err(|e| e.to_string())).await?;
+    if smart.is_empty() {
+        return Ok(());
+    }
+
+    progress.phase("smart_playlists", format!("publishing {} smart playlists...", smart.len()));
+    for sp in smart {
+        let result = publish_smart_playlist(db_path, spotify, user_id, owned_ids, &sp, progress).await;
+        if let Err(e) = &result {
+            progress.warn(format!("{} - failed ({})", sp.name, e));
+        }
+        let id = sp.id;
+        let saved = pool::run_blocking(db_path, move |conn| {
+            match &result {
+                Ok((playlist_id, count)) => {
+                    db::set_smart_playlist_published(conn, id, Some(playlist_id), Some(*count as i64), None)
+                }
+                Err(e) => db::set_smart_playlist_published(conn, id, None, None, Some(e)),
+            }
+            .map_err(|e| e.to_string())
+        })
+        .await;
+        if let Err(e) = saved {
+            progress.warn(format!("{} - couldn't save publish status ({})", sp.name, e));
+        }
+    }
+    Ok(())
+}
+
+async fn publish_smart_playlist(
+    db_path: &Path,
+    spotify: &SpotifyClient,
+    user_id: &str,
+    owned_ids: &HashSet<String>,
+    sp: &db::SmartPlaylist,
+    progress: &SyncProgress,
+) -> Result<(String, usize), String> {
+    let filter = sp.filter.clone();
+    let wanted = pool::run_blocking(db_path, move |conn| filter_track_ids(conn, &filter).map_err(|e| e.to_string())).await?;
+
+    let playlist_id = match &sp.spotify_playlist_id {
+        Some(id) => id.clone(),
+        None => {
+            let playlist = spotify
+                .create_playlist(user_id, &sp.name, Some("smart playlist from musikk"), false)
+                .await?;
+            // saved now so a failure below fills this playlist next time instead of creating another
+            let (id, playlist_id) = (sp.id, playlist.id.clone());
+            pool::run_blocking(db_path, move |conn| {
+                db::set_smart_playlist_spotify_id(conn, id, &playlist_id).map_err(|e| e.to_string())
+            })
+            .await?;
+            spotify.add_playlist_items(&playlist.id, &wanted).await?;
+            progress.info(format!("{} - created with {} tracks", sp.name, wanted.len()));
+            return Ok((playlist.id, wanted.len()));
+        }
+    };
+    // deleting a playlist on spotify only unfollows it, don't keep writing to it
+    if !owned_ids.contains(&playlist_id) {
+        return Err(format!("playlist {} is no longer in the library", playlist_id));
+    }
+
+    let current: Vec<String> = spotify
+        .get_playlist_tracks(&playlist_id)
+        .await?
+        .into_iter()
+        .filter_map(|item| item.track.and_then(|t| t.library_id()))
+        .collect();
+    let current_set: HashSet<&String> = current.iter().collect();
+    let wanted_set: HashSet<&String> = wanted.iter().collect();
+
+    let mut seen = HashSet::new();
+    let remove: Vec<String> = current
+        .iter()
+        .filter(|id| !wanted_set.contains(id) && seen.insert(*id))
+        .cloned()
+        .collect();
+    let add: Vec<String> = wanted.iter().filter(|id| !current_set.contains(id)).cloned().collect();
+
+    if !remove.is_empty() {
+        spotify.remove_playlist_items(&playlist_id, &remove).await?;
+    }
+    if !add.is_empty() {
+        spotify.add_playlist_items(&playlist_id, &add).await?;
+    }
+    if remove.is_empty() && add.is_empty() {
+        progress.info(format!("{} - unchanged ({} tracks)", sp.name, wanted.len()));
+    } else {
+        progress.info(format!("{} - {} added, {} removed", sp.name, add.len(), remove.len()));
+    }
+    Ok((playlist_id, wanted.len()))
+}
@@ -0,0 +1,73 @@
+// sqlite connections shared by the server. db work runs on tokio's blocking threads so a slow
+// query doesn't stall the async workers, and connections are kept open so their prepared
+// statement caches get reused between requests
+
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+
+use crate::db;
+
+// the pi has 4 cores, more connections than that just queue inside sqlite
+pub const DEFAULT_POOL_SIZE: usize = 4;
+
+#[derive(Clone)]
+pub struct DbPool {
+    path: PathBuf,
+    idle: Arc<Mutex<Vec<Connection>>>,
+    // one permit per connection, waiting for one doesn't hold a blocking thread
+    permits: Arc<Semaphore>,
+}
+
+impl DbPool {
+    // connections are opened lazily, the schema should already be migrated by open_db
+    pub fn new(path: PathBuf, size: usize) -> Self {
+        Self {
+            path,
+            idle: Arc::new(Mutex::new(vec![])),
+            permits: Arc::new(Semaphore::new(size.max(1))),
+        }
+    }
+
+    // runs `f` with a pooled connection on a blocking thread. the error is only for not getting
+    // a connection, whatever `f` returns (including its own errors) comes back as is
+    pub async fn run<T, F>(&self, f: F) -> Result<T, String>
+    where
+        F: FnOnce(&Connection) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = self.permits.clone().acquire_owned().await.map_err(|e| e.to_string())?;
+        let pool = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            let idle = pool.idle.lock().unwrap().pop();
+            let conn = match idle {
+                Some(c) => c,
+                None => db::connect(&pool.path).map_err(|e| e.to_string())?,
+            };
+            let result = f(&conn);
+            // a panic in `f` drops the connection instead of handing it out again
+            pool.idle.lock().unwrap().push(conn);
+            Ok(result)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+}
+
+// for async code that also runs from the cli without a pool (sync, smart playlists): runs `f` with
+// its own connection on a blocking thread
+pub async fn run_blocking<T, F>(path: &Path, f: F) -> Result<T, String>
+where
+    F: FnOnce(&mut Connection) -> Result<T, String> + Send + 'static,
+    T: Send + 'static,
+{
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut conn = db::connect(&path).map_err(|e| e.to_string())?;
+        f(&mut conn)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,191 @@
+// periodic syncs inside `musikk serve`, sharing the lock with POST /api/sync
+
+use chrono::Local;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::AppState;
+use crate::events::SyncProgress;
+use crate::sync::{self, SyncOptions};
+
+// anything shorter is almost certainly a typo and would hammer spotify
+const MIN_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone)]
+pub enum Schedule {
+    // fixed gap between the end of one sync and the start of the next
+    Every(Duration),
+    // standard 5 field cron expression in local time
+    Cron(Box<cron::Schedule>),
+}
+
+impl Schedule {
+    // "6h", "90m" or "1d", units s, m, h and d
+    pub fn parse_interval(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (num, unit) = s.split_at(split);
+        let n: u64 = num.parse().map_err(|_| format!("invalid interval '{}', expected e.g. 6h or 30m", s))?;
+        let unit_secs = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return Err(format!("invalid interval unit '{}', use s, m, h or d", unit)),
+        };
+        let secs = n.checked_mul(unit_secs).ok_or_else(|| format!("interval '{}' is too long", s))?;
+        let interval = Duration::from_secs(secs);
+        if interval < MIN_INTERVAL {
+            return Err(format!("sync interval must be at least {} minutes", MIN_INTERVAL.as_secs() / 60));
+        }
+        Ok(Schedule::Every(interval))
+    }
+
+    // "0 4 * * *" = every night at 04:00
+    pub fn parse_cron(s: &str) -> Result<Self, String> {
+        let mut fields: Vec<String> = s.split_whitespace().map(|f| f.to_string()).collect();
+        if fields.len() != 5 {
+            return Err(format!("cron expression needs 5 fields (minute hour day month weekday), got {}", fields.len()));
+        }
+        fields[4] = crate_weekdays(&fields[4]).map_err(|e| format!("invalid cron expression '{}': {}", s, e))?;
+        // the cron crate wants a seconds field first
+        let schedule = cron::Schedule::from_str(&format!("0 {}", fields.join(" ")))
+            .map_err(|e| format!("invalid cron expression '{}': {}", s, e))?;
+        if schedule.upcoming(Local).next().is_none() {
+            return Err(format!("cron expression '{}' never fires", s));
+        }
+        Ok(Schedule::Cron(Box::new(schedule)))
+    }
+
+    // how long to wait before the next run
+    fn next_delay(&self) -> Option<Duration> {
+        match self {
+            Schedule::Every(d) => Some(*d),
+            Schedule::Cron(schedule) => {
+                let next = schedule.upcoming(Local).next()?;
+                // the slot can already have started by the time we get here, run it now
+                Some((next - Local::now()).to_std().unwrap_or(Duration::ZERO))
+            }
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Schedule::Every(d) => format!("every {} minutes", d.as_secs() / 60),
+            Schedule::Cron(schedule) => match schedule.upcoming(Local).next() {
+                Some(next) => format!("on cron schedule, next at {}", next.format("%Y-%m-%d %H:%M")),
+                None => "on cron schedule".to_string(),
+            },
+        }
+    }
+}
+
+// standard cron counts weekdays 0-6 from sunday (7 is sunday too), the cron crate 1-7 from sunday.
+// numeric items are expanded to a list in the crate's numbering, names are the same in both
+fn crate_weekdays(field: &str) -> Result<String, String> {
+    let mut out: Vec<String> = vec![];
+    for item in field.split(',') {
+        if item == "*" || item == "?" || item.chars().any(|c| c.is_ascii_alphabetic()) {
+            out.push(item.to_string());
+            continue;
+        }
+        let (range, step) = match item.split_once('/') {
+            Some((r, s)) => (r, s.parse::<usize>().map_err(|_| format!("invalid weekday step '{}'", s))?),
+            None => (item, 1),
+        };
+        let day = |d: &str| match d.parse::<u32>() {
+            Ok(n) if n <= 7 => Ok(n),
+            _ => Err(format!("invalid weekday '{}', use 0-7 or sun-sat", d)),
+        };
+        let (from, to) = match range.split_once('-') {
+            _ if range == "*" => (0, 6),
+            Some((a, b)) => (day(a)?, day(b)?),
+            // "1/2" runs from 1 to the end of the week
+            None if step > 1 => (day(range)?, 6),
+            None => (day(range)?, day(range)?),
+        };
+        if from > to || step == 0 {
+            return Err(format!("invalid weekday range '{}'", item));
+        }
+        for d in (from..=to).step_by(step) {
+            out.push((d % 7 + 1).to_string());
+        }
+    }
+    Ok(out.join(","))
+}
+
+pub async fn run(state: Arc<AppState>, schedule: Schedule) {
+    println!("scheduled sync {}", schedule.describe());
+    loop {
+        let delay = match schedule.next_delay() {
+            Some(d) => d,
+            None => {
+                println!("sync schedule has no more runs, stopping");
+                return;
+            }
+        };
+        tokio::time::sleep(delay).await;
+
+        // a manual sync that's already running covers this slot
+        let guard = match state.sync.try_start() {
+            Some(g) => g,
+            None => {
+                println!("scheduled sync skipped, a sync is already running");
+                continue;
+            }
+        };
+        let progress = SyncProgress::new(Some(state.events.clone())).with_status(guard.status());
+        // the outcome is already printed, sent to subscribers and written to sync_log
+        let _ = sync::run_logged_sync(&state.db_path, &state.spotify, &SyncOptions::default(), &progress).await;
+        drop(guard);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Timelike, Weekday};
+
+    fn next_days(expr: &str, n: usize) -> Vec<Weekday> {
+        match Schedule::parse_cron(expr).unwrap() {
+            Schedule::Cron(schedule) => schedule
+                .upcoming(Local)
+                .take(n)
+                .map(|t| {
+                    assert_eq!((t.hour(), t.minute()), (4, 0));
+                    t.weekday()
+                })
+                .collect(),
+            Schedule::Every(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn weekdays_are_monday_to_friday() {
+        let days = next_days("0 4 * * 1-5", 10);
+        assert!(days.iter().all(|d| !matches!(d, Weekday::Sat | Weekday::Sun)), "{:?}", days);
+        for day in [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri] {
+            assert!(days.contains(&day), "{:?} missing from {:?}", day, days);
+        }
+    }
+
+    #[test]
+    fn zero_and_seven_are_sunday() {
+        assert!(next_days("0 4 * * 0", 3).iter().all(|d| *d == Weekday::Sun));
+        assert!(next_days("0 4 * * 7", 3).iter().all(|d| *d == Weekday::Sun));
+        assert!(next_days("0 4 * * sat,0", 4).iter().all(|d| matches!(d, Weekday::Sat | Weekday::Sun)));
+    }
+
+    #[test]
+    fn weekday_field_translation() {
+        assert_eq!(crate_weekdays("*").unwrap(), "*");
+        assert_eq!(crate_weekdays("0").unwrap(), "1");
+        assert_eq!(crate_weekdays("1-5").unwrap(), "2,3,4,5,6");
+        assert_eq!(crate_weekdays("5-7").unwrap(), "6,7,1");
+        assert_eq!(crate_weekdays("*/2").unwrap(), "1,3,5,7");
+        assert_eq!(crate_weekdays("Mon-Fri").unwrap(), "Mon-Fri");
+        assert!(crate_weekdays("8").is_err());
+        assert!(crate_weekdays("5-1").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+// "more like this": ranks tracks by weighted distance over min-max normalized audio features
+
+use crate::db::Track;
+
+// features used by default, key and mode are left to the camelot constraint
+const DEFAULT_WEIGHTS: &[(&str, f64)] = &[
+    ("tempo", 1.0),
+    ("energy", 1.0),
+    ("danceability", 1.0),
+    ("valence", 1.0),
+    ("acousticness", 1.0),
+    ("instrumentalness", 1.0),
+    ("speechiness", 0.5),
+    ("liveness", 0.5),
+    ("loudness", 0.5),
+];
+
+// everything that can be given a weight
+const FEATURES: &[&str] = &[
+    "tempo",
+    "energy",
+    "danceability",
+    "valence",
+    "acousticness",
+    "instrumentalness",
+    "speechiness",
+    "liveness",
+    "loudness",
+    "duration_ms",
+    "popularity",
+];
+
+pub fn feature(track: &Track, name: &str) -> Option<f64> {
+    match name {
+        "tempo" => track.tempo,
+        "energy" => track.energy,
+        "danceability" => track.danceability,
+        "valence" => track.valence,
+        "acousticness" => track.acousticness,
+        "instrumentalness" => track.instrumentalness,
+        "speechiness" => track.speechiness,
+        "liveness" => track.liveness,
+        "loudness" => track.loudness,
+        "duration_ms" => track.duration_ms.map(|v| v as f64),
+        "popularity" => track.popularity.map(|v| v as f64),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Weights(Vec<(&'static str, f64)>);
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights(DEFAULT_WEIGHTS.to_vec())
+    }
+}
+
+impl Weights {
+    // "energy:2,tempo:0.5" on top of the defaults, a weight of 0 drops the feature
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut weights = Weights::default();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = part
+                .split_once(':')
+                .ok_or_else(|| format!("weight must look like feature:number, got '{}'", part))?;
+            let name = FEATURES
+                .iter()
+                .find(|f| **f == name.trim())
+                .copied()
+                .ok_or_else(|| format!("unknown feature '{}'", name.trim()))?;
+            let value: f64 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid weight for {}: '{}'", name, value.trim()))?;
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!("weight for {} must be zero or more", name));
+            }
+            match weights.0.iter_mut().find(|(f, _)| *f == name) {
+                Some(w) => w.1 = value,
+                None => weights.0.push((name, value)),
+            }
+        }
+        weights.0.retain(|(_, w)| *w > 0.0);
+        if weights.0.is_empty() {
+            return Err("at least one feature needs a weight above 0".to_string());
+        }
+        Ok(weights)
+    }
+}
+
+// candidates closest to the seed first, with their distance (0 = identical, 1 = opposite ends on
+// every feature). features are scaled by their min/max over the seed and candidates so tempo and
+// loudness don't drown out the 0..1 ones. candidates missing a weighted feature, or the seed itself,
+// are skipped
+pub fn rank(seed: &Track, candidates: Vec<Track>, weights: &Weights, limit: usize) -> Result<Vec<(Track, f64)>, String> {
+    let seed_values: Vec<f64> = weights
+        .0
+        .iter()
+        .map(|(f, _)| feature(seed, f).ok_or_else(|| format!("track {} has no {}", seed.spotify_id, f)))
+        .collect::<Result<_, _>>()?;
+
+    let candidates: Vec<(Track, Vec<f64>)> = candidates
+        .into_iter()
+        .filter(|t| t.spotify_id != seed.spotify_id)
+        .filter_map(|t| {
+            let values = weights.0.iter().map(|(f, _)| feature(&t, f)).collect::<Option<Vec<f64>>>()?;
+            Some((t, values))
+        })
+        .collect();
+
+    let spans: Vec<f64> = (0..seed_values.len())
+        .map(|i| {
+            let values = candidates.iter().map(|(_, v)| v[i]).chain(std::iter::once(seed_values[i]));
+            let min = values.clone().fold(f64::INFINITY, f64::min);
+            let max = values.fold(f64::NEG_INFINITY, f64::max);
+            max - min
+        })
+        .collect();
+    let total_weight: f64 = weights.0.iter().map(|(_, w)| w).sum();
+
+    let mut ranked: Vec<(Track, f64)> = candidates
+        .into_iter()
+        .map(|(t, values)| {
+            let sum: f64 = values
+                .iter()
+                .zip(&seed_values)
+                .zip(&spans)
+                .zip(&weights.0)
+                .map(|(((v, s), span), (_, w))| {
+                    // every value is the same, nothing to tell apart on this feature
+                    if *span == 0.0 {
+                        return 0.0;
+                    }
+                    w * ((v - s) / span).powi(2)
+                })
+                .sum();
+            (t, (sum / total_weight).sqrt())
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.spotify_id.cmp(&b.0.spotify_id)));
+    ranked.truncate(limit);
+    Ok(ranked)
+}
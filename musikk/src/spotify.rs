@@ -1,17 +1,20 @@
 #![allow(dead_code)]
 
-use reqwest::Client;
 use serde::Deserialize;
 use std::collections::HashMap;
 
+use crate::http::{HttpClient, RetryLog};
+
 const SPOTIFY_AUTH_URL: &str = "https://accounts.spotify.com/authorize";
 const SPOTIFY_TOKEN_URL: &str = "https://accounts.spotify.com/api/token";
 const SPOTIFY_API_URL: &str = "https://api.spotify.com/v1";
 const RECCOBEATS_API_URL: &str = "https://api.reccobeats.com/v1";
+// spotify takes at most this many uris per playlist items request
+const PLAYLIST_ITEMS_PER_REQUEST: usize = 100;
 
 #[derive(Debug, Clone)]
 pub struct SpotifyClient {
-    client: Client,
+    http: HttpClient,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
@@ -34,6 +37,24 @@ pub struct SpotifyTrack {
     pub duration_ms: i64,
     #[serde(default)]
     pub popularity: i64,
+    pub is_playable: Option<bool>,  // only set when requested with a market
+    // with a market spotify may swap in a playable copy, this is the track that's actually saved
+    pub linked_from: Option<LinkedTrack>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LinkedTrack {
+    pub id: String,
+}
+
+impl SpotifyTrack {
+    // the id the library knows the track by, not the relinked copy
+    pub fn library_id(&self) -> Option<String> {
+        match &self.linked_from {
+            Some(l) => Some(l.id.clone()),
+            None => self.id.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, serde::Serialize)]
@@ -58,6 +79,7 @@ pub struct SpotifyAlbum {
 
 #[derive(Debug, Deserialize)]
 pub struct SavedTrack {
+    pub added_at: Option<String>,
     pub track: SpotifyTrack,
 }
 
@@ -84,6 +106,14 @@ pub struct AlbumTrack {
     pub name: String,
     pub artists: Vec<SpotifyArtist>,
     pub duration_ms: i64,
+    pub is_playable: Option<bool>,
+    pub linked_from: Option<LinkedTrack>,
+}
+
+impl AlbumTrack {
+    pub fn library_id(&self) -> String {
+        self.linked_from.as_ref().map(|l| l.id.clone()).unwrap_or_else(|| self.id.clone())
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -91,15 +121,19 @@ pub struct Playlist {
     pub id: String,
     pub name: String,
     pub owner: PlaylistOwner,
+    pub snapshot_id: Option<String>,
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct PlaylistOwner {
     pub id: String,
+    pub display_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct PlaylistTrack {
+    pub added_at: Option<String>,
     pub track: Option<SpotifyTrack>,
 }
 
@@ -146,7 +180,7 @@ pub struct ReccoTrack {
 impl SpotifyClient {
     pub fn new(client_id: String, client_secret: String, redirect_uri: String) -> Self {
         Self {
-            client: Client::new(),
+            http: HttpClient::new("spotify", 4),
             client_id,
             client_secret,
             redirect_uri,
@@ -154,8 +188,13 @@ impl SpotifyClient {
         }
     }
 
+    // retry notices from this clone's requests go to `log`, the rate limits stay shared
+    pub fn with_retry_log(self, log: RetryLog) -> Self {
+        Self { http: self.http.with_retry_log(log), ..self }
+    }
+
     pub fn auth_url(&self) -> String {
-        let scopes = "user-library-read playlist-read-private playlist-read-collaborative user-modify-playback-state user-read-playback-state user-read-currently-playing";
+        let scopes = "user-library-read playlist-read-private playlist-read-collaborative playlist-modify-public playlist-modify-private user-modify-playback-state user-read-playback-state user-read-currently-playing";
         format!(
             "{}?client_id={}&response_type=code&redirect_uri={}&scope={}",
             SPOTIFY_AUTH_URL,
@@ -167,12 +206,10 @@ impl SpotifyClient {
 
     pub async fn get_playback_state(&self) -> Result<Option<PlaybackState>, String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
-        let resp = self.client
+        let req = self.http
             .get(&format!("{}/me/player", SPOTIFY_API_URL))
-            .bearer_auth(token)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .bearer_auth(token);
+        let resp = self.http.send(req).await?;
 
         if resp.status().as_u16() == 204 {
             return Ok(None); // no active device
@@ -190,13 +227,11 @@ impl SpotifyClient {
         let uri = format!("spotify:track:{}", track_id);
         let url = format!("{}/me/player/queue?uri={}", SPOTIFY_API_URL, urlencoding::encode(&uri));
         
-        let resp = self.client
+        let req = self.http
             .post(&url)
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -210,13 +245,11 @@ impl SpotifyClient {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         let uri = format!("spotify:track:{}", track_id);
         
-        let resp = self.client
+        let req = self.http
             .put(&format!("{}/me/player/play", SPOTIFY_API_URL))
             .bearer_auth(token)
-            .json(&serde_json::json!({ "uris": [uri] }))
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .json(&serde_json::json!({ "uris": [uri] }));
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -229,13 +262,11 @@ impl SpotifyClient {
     pub async fn pause(&self) -> Result<(), String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         
-        let resp = self.client
+        let req = self.http
             .put(&format!("{}/me/player/pause", SPOTIFY_API_URL))
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -248,13 +279,11 @@ impl SpotifyClient {
     pub async fn resume(&self) -> Result<(), String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         
-        let resp = self.client
+        let req = self.http
             .put(&format!("{}/me/player/play", SPOTIFY_API_URL))
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -267,13 +296,11 @@ impl SpotifyClient {
     pub async fn skip_next(&self) -> Result<(), String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         
-        let resp = self.client
+        let req = self.http
             .post(&format!("{}/me/player/next", SPOTIFY_API_URL))
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -286,13 +313,11 @@ impl SpotifyClient {
     pub async fn skip_prev(&self) -> Result<(), String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         
-        let resp = self.client
+        let req = self.http
             .post(&format!("{}/me/player/previous", SPOTIFY_API_URL))
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -305,13 +330,11 @@ impl SpotifyClient {
     pub async fn seek(&self, position_ms: i64) -> Result<(), String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
         
-        let resp = self.client
+        let req = self.http
             .put(&format!("{}/me/player/seek?position_ms={}", SPOTIFY_API_URL, position_ms))
             .bearer_auth(token)
-            .header("Content-Length", "0")
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .header("Content-Length", "0");
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -327,13 +350,11 @@ impl SpotifyClient {
         params.insert("code", code);
         params.insert("redirect_uri", &self.redirect_uri);
 
-        let resp = self.client
+        let req = self.http
             .post(SPOTIFY_TOKEN_URL)
             .basic_auth(&self.client_id, Some(&self.client_secret))
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .form(&params);
+        let resp = self.http.send(req).await?;
 
         let token: TokenResponse = resp.json().await.map_err(|e| e.to_string())?;
         self.access_token = Some(token.access_token.clone());
@@ -345,13 +366,11 @@ impl SpotifyClient {
         params.insert("grant_type", "refresh_token");
         params.insert("refresh_token", refresh_token);
 
-        let resp = self.client
+        let req = self.http
             .post(SPOTIFY_TOKEN_URL)
             .basic_auth(&self.client_id, Some(&self.client_secret))
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .form(&params);
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -369,12 +388,10 @@ impl SpotifyClient {
 
     async fn get<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, String> {
         let token = self.access_token.as_ref().ok_or("no access token")?;
-        let resp = self.client
+        let req = self.http
             .get(url)
-            .bearer_auth(token)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .bearer_auth(token);
+        let resp = self.http.send(req).await?;
 
         if !resp.status().is_success() {
             let text = resp.text().await.unwrap_or_default();
@@ -385,24 +402,42 @@ impl SpotifyClient {
     }
 
     pub async fn get_saved_tracks(&self) -> Result<Vec<SavedTrack>, String> {
+        let (all, _) = self.get_saved_tracks_since(None).await?;
+        Ok(all)
+    }
+
+    // liked songs come newest first, so paging stops at the first one added at or before `since`.
+    // also returns spotify's total so callers can tell if anything was unliked
+    pub async fn get_saved_tracks_since(&self, since: Option<&str>) -> Result<(Vec<SavedTrack>, i64), String> {
         let mut all = vec![];
-        let mut url = format!("{}/me/tracks?limit=50", SPOTIFY_API_URL);
+        let mut url = format!("{}/me/tracks?limit=50&market=from_token", SPOTIFY_API_URL);
+        let mut total;
 
         loop {
             let page: Paged<SavedTrack> = self.get(&url).await?;
-            all.extend(page.items);
+            total = page.total;
+            for item in page.items {
+                let known = match (since, item.added_at.as_deref()) {
+                    (Some(since), Some(added_at)) => added_at <= since,
+                    _ => false,
+                };
+                if known {
+                    return Ok((all, total));
+                }
+                all.push(item);
+            }
             match page.next {
                 Some(next) => url = next,
                 None => break,
             }
         }
 
-        Ok(all)
+        Ok((all, total))
     }
 
     pub async fn get_saved_albums(&self) -> Result<Vec<SavedAlbum>, String> {
         let mut all = vec![];
-        let mut url = format!("{}/me/albums?limit=50", SPOTIFY_API_URL);
+        let mut url = format!("{}/me/albums?limit=50&market=from_token", SPOTIFY_API_URL);
 
         loop {
             let page: Paged<SavedAlbum> = self.get(&url).await?;
@@ -441,7 +476,7 @@ impl SpotifyClient {
 
     pub async fn get_playlist_tracks(&self, playlist_id: &str) -> Result<Vec<PlaylistTrack>, String> {
         let mut all = vec![];
-        let mut url = format!("{}/playlists/{}/tracks?limit=100", SPOTIFY_API_URL, playlist_id);
+        let mut url = format!("{}/playlists/{}/tracks?limit=100&market=from_token", SPOTIFY_API_URL, playlist_id);
 
         loop {
             let page: Paged<PlaylistTrack> = self.get(&url).await?;
@@ -455,6 +490,107 @@ impl SpotifyClient {
         Ok(all)
     }
 
+    pub async fn create_playlist(
+        &self,
+        user_id: &str,
+        name: &str,
+        description: Option<&str>,
+        public: bool,
+    ) -> Result<Playlist, String> {
+        let token = self.access_token.as_ref().ok_or("no access token")?;
+
+        let req = self.http
+            .post(&format!("{}/users/{}/playlists", SPOTIFY_API_URL, user_id))
+            .bearer_auth(token)
+            .json(&serde_json::json!({
+                "name": name,
+                "description": description.unwrap_or(""),
+                "public": public,
+            }));
+        let resp = self.http.send(req).await?;
+
+        if !resp.status().is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(format!("create playlist failed: {}", text));
+        }
+
+        resp.json().await.map_err(|e| e.to_string())
+    }
+
+    // replaces everything in the playlist with these tracks, in order. returns the new snapshot id
+    pub async fn replace_playlist_items(&self, playlist_id: &str, track_ids: &[String]) -> Result<String, String> {
+        let token = self.access_token.as_ref().ok_or("no access token")?;
+        let (first, rest) = track_ids.split_at(track_ids.len().min(PLAYLIST_ITEMS_PER_REQUEST));
+
+        // the replace call only takes one chunk, an empty list clears the playlist
+        let req = self.http
+            .put(&format!("{}/playlists/{}/tracks", SPOTIFY_API_URL, playlist_id))
+            .bearer_auth(token)
+            .json(&serde_json::json!({ "uris": track_uris(first) }));
+        let resp = self.http.send(req).await?;
+
+        if !resp.status().is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(format!("replace playlist items failed: {}", text));
+        }
+
+        let mut snapshot = resp.json::<SnapshotResponse>().await.map_err(|e| e.to_string())?.snapshot_id;
+        if !rest.is_empty() {
+            snapshot = self.add_playlist_items(playlist_id, rest).await?;
+        }
+        Ok(snapshot)
+    }
+
+    // appends tracks to the end of the playlist. returns the new snapshot id
+    pub async fn add_playlist_items(&self, playlist_id: &str, track_ids: &[String]) -> Result<String, String> {
+        let token = self.access_token.as_ref().ok_or("no access token")?;
+        let mut snapshot = String::new();
+
+        for chunk in track_ids.chunks(PLAYLIST_ITEMS_PER_REQUEST) {
+            let req = self.http
+                .post(&format!("{}/playlists/{}/tracks", SPOTIFY_API_URL, playlist_id))
+                .bearer_auth(token)
+                .json(&serde_json::json!({ "uris": track_uris(chunk) }));
+            let resp = self.http.send(req).await?;
+
+            if !resp.status().is_success() {
+                let text = resp.text().await.unwrap_or_default();
+                return Err(format!("add playlist items failed: {}", text));
+            }
+
+            snapshot = resp.json::<SnapshotResponse>().await.map_err(|e| e.to_string())?.snapshot_id;
+        }
+
+        Ok(snapshot)
+    }
+
+    // removes every occurrence of these tracks from the playlist. returns the new snapshot id
+    pub async fn remove_playlist_items(&self, playlist_id: &str, track_ids: &[String]) -> Result<String, String> {
+        let token = self.access_token.as_ref().ok_or("no access token")?;
+        let mut snapshot = String::new();
+
+        for chunk in track_ids.chunks(PLAYLIST_ITEMS_PER_REQUEST) {
+            let uris: Vec<serde_json::Value> = track_uris(chunk)
+                .into_iter()
+                .map(|uri| serde_json::json!({ "uri": uri }))
+                .collect();
+            let req = self.http
+                .delete(&format!("{}/playlists/{}/tracks", SPOTIFY_API_URL, playlist_id))
+                .bearer_auth(token)
+                .json(&serde_json::json!({ "tracks": uris }));
+            let resp = self.http.send(req).await?;
+
+            if !resp.status().is_success() {
+                let text = resp.text().await.unwrap_or_default();
+                return Err(format!("remove playlist items failed: {}", text));
+            }
+
+            snapshot = resp.json::<SnapshotResponse>().await.map_err(|e| e.to_string())?.snapshot_id;
+        }
+
+        Ok(snapshot)
+    }
+
     pub async fn get_audio_features_batch(&self, ids: &[String]) -> Result<Vec<SpotifyAudioFeatures>, String> {
         if ids.is_empty() {
             return Ok(vec![]);
@@ -491,6 +627,15 @@ impl SpotifyClient {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct SnapshotResponse {
+    snapshot_id: String,
+}
+
+fn track_uris(track_ids: &[String]) -> Vec<String> {
+    track_ids.iter().map(|id| format!("spotify:track:{}", id)).collect()
+}
+
 #[derive(Debug, Deserialize, Clone, serde::Serialize)]
 pub struct PlaybackState {
     pub is_playing: bool,
@@ -522,8 +667,9 @@ pub struct SpotifyAudioFeatures {
     pub loudness: f64,
 }
 
+#[derive(Debug, Clone)]
 pub struct ReccobeatsClient {
-    client: Client,
+    http: HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -565,7 +711,15 @@ pub struct ReccoAudioFeatures {
 
 impl ReccobeatsClient {
     pub fn new() -> Self {
-        Self { client: Client::new() }
+        Self::with_concurrency(4)
+    }
+
+    pub fn with_concurrency(max_in_flight: usize) -> Self {
+        Self { http: HttpClient::new("reccobeats", max_in_flight) }
+    }
+
+    pub fn with_retry_log(self, log: RetryLog) -> Self {
+        Self { http: self.http.with_retry_log(log) }
     }
 
     // step 1: get recco track ids from spotify ids
@@ -576,7 +730,7 @@ impl ReccobeatsClient {
 
         let ids_str = spotify_ids.join(",");
         let url = format!("{}/track?ids={}", RECCOBEATS_API_URL, ids_str);
-        let resp = self.client.get(&url).send().await.map_err(|e| e.to_string())?;
+        let resp = self.http.send(self.http.get(&url)).await?;
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -591,14 +745,16 @@ impl ReccobeatsClient {
     // step 2: get audio features by recco track id
     pub async fn get_audio_features(&self, recco_id: &str) -> Result<Option<ReccoAudioFeatures>, String> {
         let url = format!("{}/track/{}/audio-features", RECCOBEATS_API_URL, recco_id);
-        let resp = self.client.get(&url).send().await.map_err(|e| e.to_string())?;
+        let resp = self.http.send(self.http.get(&url)).await?;
 
         if resp.status().as_u16() == 404 {
             return Ok(None);
         }
 
         if !resp.status().is_success() {
-            return Ok(None);
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            return Err(format!("recco features failed {}: {}", status, text));
         }
 
         let features: ReccoAudioFeatures = resp.json().await.map_err(|e| e.to_string())?;
@@ -1,8 +1,12 @@
-use crate::db::{self, Track};
+use crate::db::{self, FeatureStatus, Track};
+use crate::events::SyncProgress;
+use crate::pool;
 use crate::spotify::{SpotifyClient, ReccobeatsClient};
-use rusqlite::Connection;
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinSet;
 
 pub struct SyncResult {
     pub added: i64,
@@ -10,41 +14,226 @@ pub struct SyncResult {
     pub unavailable: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct SyncOptions {
+    pub dry_run: bool,
+    pub backfill: bool,
+    // refetch every liked song and playlist instead of only what changed
+    pub full: bool,
+    // reccobeats requests in flight at once
+    pub concurrency: usize,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            backfill: false,
+            full: false,
+            concurrency: 4,
+        }
+    }
+}
+
+// what the running (or last) sync in this process is doing
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncStatus {
+    pub running: bool,
+    pub log_id: Option<i64>,
+    pub started_at: Option<String>,
+    pub phase: Option<&'static str>,
+    pub message: Option<String>,
+    pub done: Option<usize>,
+    pub total: Option<usize>,
+    // warnings so far, plus the failure if the sync failed
+    pub errors: usize,
+}
+
+// one sync at a time per process, shared by everything that can start one
+#[derive(Debug, Clone, Default)]
+pub struct SyncTracker {
+    status: Arc<Mutex<SyncStatus>>,
+}
+
+impl SyncTracker {
+    // None if a sync is already running
+    pub fn try_start(&self) -> Option<SyncGuard> {
+        let mut status = self.status.lock().unwrap();
+        if status.running {
+            return None;
+        }
+        *status = SyncStatus {
+            running: true,
+            started_at: Some(chrono::Utc::now().to_rfc3339()),
+            ..Default::default()
+        };
+        Some(SyncGuard { status: self.status.clone() })
+    }
+
+    pub fn status(&self) -> SyncStatus {
+        self.status.lock().unwrap().clone()
+    }
+}
+
+// holds the sync lock, released on drop even if the sync panics
+#[derive(Debug)]
+pub struct SyncGuard {
+    status: Arc<Mutex<SyncStatus>>,
+}
+
+impl SyncGuard {
+    pub fn status(&self) -> Arc<Mutex<SyncStatus>> {
+        self.status.clone()
+    }
+}
+
+impl Drop for SyncGuard {
+    fn drop(&mut self) {
+        self.status.lock().unwrap().running = false;
+    }
+}
+
+// run_sync with a sync_log row around it, dry runs aren't logged.
+// errors are reported through progress before they're returned
+pub async fn run_logged_sync(
+    db_path: &Path,
+    spotify: &SpotifyClient,
+    opts: &SyncOptions,
+    progress: &SyncProgress,
+) -> Result<SyncResult, String> {
+    let log_id = if opts.dry_run {
+        None
+    } else {
+        let path = db_path.to_path_buf();
+        let started = tokio::task::spawn_blocking(move || db::open_db(&path).and_then(|conn| db::start_sync_log(&conn)))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|r| r.map_err(|e| e.to_string()));
+        match started {
+            Ok(id) => Some(id),
+            Err(e) => {
+                progress.failed(&e);
+                return Err(e);
+            }
+        }
+    };
+    progress.set_log_id(log_id);
+
+    let result = run_sync(db_path, spotify, opts, progress).await;
+    if let Some(id) = log_id {
+        let outcome = result.as_ref().map(|r| (r.added, r.updated, r.unavailable)).map_err(|e| e.clone());
+        let finished = pool::run_blocking(db_path, move |conn| {
+            match outcome {
+                Ok((added, updated, unavailable)) => db::finish_sync_log(conn, id, added, updated, unavailable, None),
+                Err(e) => db::finish_sync_log(conn, id, 0, 0, 0, Some(&e)),
+            }
+            .map_err(|e| e.to_string())
+        })
+        .await;
+        if let Err(e) = finished {
+            progress.warn(format!("couldn't record sync in sync_log: {}", e));
+        }
+    }
+    result
+}
+
 pub async fn run_sync(
     db_path: &Path,
-    spotify: &mut SpotifyClient,
-    dry_run: bool,
-    backfill: bool,
+    spotify: &SpotifyClient,
+    opts: &SyncOptions,
+    progress: &SyncProgress,
+) -> Result<SyncResult, String> {
+    let result = sync_library(db_path, spotify, opts, progress).await;
+    if let Err(ref e) = result {
+        progress.failed(e);
+    }
+    result
+}
+
+async fn sync_library(
+    db_path: &Path,
+    spotify: &SpotifyClient,
+    opts: &SyncOptions,
+    progress: &SyncProgress,
 ) -> Result<SyncResult, String> {
-    // open db just to get refresh token
-    let refresh_token = {
-        let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
-        db::get_config(&conn, "spotify_refresh_token")
+    let SyncOptions { dry_run, backfill, full, concurrency } = *opts;
+
+    // open db to get refresh token and what we already know from the last sync
+    let (refresh_token, liked_since, known_snapshots) = pool::run_blocking(db_path, move |conn| {
+        let refresh_token = db::get_config(conn, "spotify_refresh_token")
             .map_err(|e| e.to_string())?
-            .ok_or("no refresh token - run 'musikk auth' first")?
-    };
+            .ok_or("no refresh token - run 'musikk auth' first")?;
+        let liked_since = if full {
+            None
+        } else {
+            db::get_config(conn, "liked_newest_added_at").map_err(|e| e.to_string())?
+        };
+        let known_snapshots: HashMap<String, String> = if full {
+            HashMap::new()
+        } else {
+            db::get_playlists(conn)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .filter_map(|p| p.snapshot_id.map(|s| (p.id, s)))
+                .collect()
+        };
+        Ok((refresh_token, liked_since, known_snapshots))
+    })
+    .await?;
 
+    // a clone shares the caller's rate limiting, in the server that's the client every route uses
+    let mut spotify = spotify.clone().with_retry_log(progress.retry_log());
     let token = spotify.refresh_token(&refresh_token).await?;
 
     let user_id = spotify.get_user_id().await?;
-    println!("syncing library for user: {}", user_id);
+    progress.phase("started", format!("syncing library for user: {}", user_id));
 
     let mut tracks: HashMap<String, Track> = HashMap::new();
     let mut track_artist_ids: HashMap<String, Vec<String>> = HashMap::new(); // track_id -> artist_ids
+    let mut not_playable: HashSet<String> = HashSet::new();
+    // only mark missing tracks as removed if every source was fetched
+    let mut complete = true;
+    // tracks we didn't refetch because their source is unchanged, and which liked/album sources
+    // they're in. playlist membership lives in playlist_tracks, those only need the key
+    let mut kept_sources: HashMap<String, Vec<String>> = HashMap::new();
 
-    // fetch liked songs
-    println!("fetching liked songs...");
-    let saved = spotify.get_saved_tracks().await?;
-    println!("  found {} liked songs", saved.len());
+    // fetch liked songs, only the ones added since last sync unless something was unliked
+    progress.phase("liked", "fetching liked songs...");
+    let (mut saved, liked_total) = spotify.get_saved_tracks_since(liked_since.as_deref()).await?;
+    let mut liked_refetched = true;
+    if liked_since.is_some() {
+        let known_liked =
+            pool::run_blocking(db_path, |conn| db::get_source_track_ids(conn, "liked").map_err(|e| e.to_string())).await?;
+        let mut liked_ids: HashSet<String> = known_liked.iter().cloned().collect();
+        liked_ids.extend(saved.iter().filter_map(|st| st.track.library_id()));
+        if liked_ids.len() as i64 == liked_total {
+            progress.info(format!("{} new liked songs, {} unchanged", saved.len(), known_liked.len()));
+            for id in known_liked {
+                kept_sources.entry(id).or_default().push("liked".to_string());
+            }
+            liked_refetched = false;
+        } else {
+            progress.info("liked songs changed since last sync, refetching all");
+            saved = spotify.get_saved_tracks().await?;
+        }
+    }
+    let liked_newest = saved.first().and_then(|st| st.added_at.clone());
+    // the complete set of liked songs, when we have it
+    let liked_now: Option<HashSet<String>> =
+        liked_refetched.then(|| saved.iter().filter_map(|st| st.track.library_id()).collect());
+    progress.info(format!("found {} liked songs", saved.len()));
     for st in saved {
         let t = &st.track;
-        let track_id = match &t.id {
-            Some(id) => id.clone(),
+        let track_id = match t.library_id() {
+            Some(id) => id,
             None => continue,  // skip local files
         };
         let artists: Vec<String> = t.artists.iter().map(|a| a.name.clone()).collect();
         let artist_ids: Vec<String> = t.artists.iter().map(|a| a.id.clone()).collect();
         track_artist_ids.insert(track_id.clone(), artist_ids);
+        if t.is_playable == Some(false) {
+            not_playable.insert(track_id.clone());
+        }
         let entry = tracks.entry(track_id.clone()).or_insert_with(|| Track {
             spotify_id: track_id.clone(),
             recco_id: None,
@@ -68,6 +257,8 @@ pub async fn run_sync(
             liveness: None,
             loudness: None,
             unavailable: false,
+            unavailable_reason: None,
+            unavailable_since: None,
             first_seen: None,
             last_seen: None,
             updated: None,
@@ -76,17 +267,21 @@ pub async fn run_sync(
     }
 
     // fetch saved albums
-    println!("fetching saved albums...");
+    progress.phase("albums", "fetching saved albums...");
     let albums = spotify.get_saved_albums().await?;
-    println!("  found {} saved albums", albums.len());
+    progress.info(format!("found {} saved albums", albums.len()));
     for sa in albums {
         let album = &sa.album;
         for t in &album.tracks.items {
+            let track_id = t.library_id();
             let artists: Vec<String> = t.artists.iter().map(|a| a.name.clone()).collect();
             let artist_ids: Vec<String> = t.artists.iter().map(|a| a.id.clone()).collect();
-            track_artist_ids.entry(t.id.clone()).or_insert(artist_ids);
-            let entry = tracks.entry(t.id.clone()).or_insert_with(|| Track {
-                spotify_id: t.id.clone(),
+            track_artist_ids.entry(track_id.clone()).or_insert(artist_ids);
+            if t.is_playable == Some(false) {
+                not_playable.insert(track_id.clone());
+            }
+            let entry = tracks.entry(track_id.clone()).or_insert_with(|| Track {
+                spotify_id: track_id.clone(),
                 recco_id: None,
                 name: t.name.clone(),
                 artists: Some(serde_json::to_string(&artists).unwrap()),
@@ -108,6 +303,8 @@ pub async fn run_sync(
                 liveness: None,
                 loudness: None,
                 unavailable: false,
+                unavailable_reason: None,
+                unavailable_since: None,
                 first_seen: None,
                 last_seen: None,
                 updated: None,
@@ -117,30 +314,61 @@ pub async fn run_sync(
     }
 
     // fetch owned playlists
-    println!("fetching playlists...");
+    progress.phase("playlists", "fetching playlists...");
     let playlists = spotify.get_playlists().await?;
     let owned: Vec<_> = playlists.into_iter().filter(|p| p.owner.id == user_id).collect();
-    println!("  found {} owned playlists", owned.len());
+    progress.info(format!("found {} owned playlists", owned.len()));
+    let owned_ids: HashSet<String> = owned.iter().map(|p| p.id.clone()).collect();
+    // entries are None for playlists whose snapshot didn't change
+    let mut playlist_entries: Vec<(db::Playlist, Option<Vec<db::PlaylistEntry>>)> = vec![];
 
     for playlist in owned {
+        let unchanged = playlist.snapshot_id.is_some()
+            && known_snapshots.get(&playlist.id) == playlist.snapshot_id.as_ref();
+        let info = db::Playlist {
+            id: playlist.id.clone(),
+            name: playlist.name.clone(),
+            snapshot_id: playlist.snapshot_id.clone(),
+            owner: playlist.owner.display_name.clone().or(Some(playlist.owner.id.clone())),
+            description: playlist.description.clone().filter(|d| !d.is_empty()),
+            track_count: 0,
+        };
+
+        if unchanged {
+            let id = playlist.id.clone();
+            let entries =
+                pool::run_blocking(db_path, move |conn| db::get_playlist_tracks(conn, &id).map_err(|e| e.to_string())).await?;
+            progress.info(format!("{} - unchanged ({} tracks)", playlist.name, entries.len()));
+            for e in &entries {
+                kept_sources.entry(e.spotify_id.clone()).or_default();
+            }
+            playlist_entries.push((db::Playlist { track_count: entries.len() as i64, ..info }, None));
+            continue;
+        }
+
         let pt = match spotify.get_playlist_tracks(&playlist.id).await {
             Ok(tracks) => tracks,
             Err(e) => {
-                println!("  {} - skipped ({})", playlist.name, e);
+                progress.warn(format!("{} - skipped ({})", playlist.name, e));
+                complete = false;
                 continue;
             }
         };
-        println!("  {} - {} tracks", playlist.name, pt.len());
-        for item in pt {
+        progress.info(format!("{} - {} tracks", playlist.name, pt.len()));
+        let mut entries = vec![];
+        for (position, item) in pt.into_iter().enumerate() {
             if let Some(t) = item.track {
-                let track_id = match &t.id {
-                    Some(id) => id.clone(),
+                let track_id = match t.library_id() {
+                    Some(id) => id,
                     None => continue,  // skip local files
                 };
                 let artists: Vec<String> = t.artists.iter().map(|a| a.name.clone()).collect();
                 let artist_ids: Vec<String> = t.artists.iter().map(|a| a.id.clone()).collect();
                 track_artist_ids.entry(track_id.clone()).or_insert(artist_ids);
-                let entry = tracks.entry(track_id.clone()).or_insert_with(|| Track {
+                if t.is_playable == Some(false) {
+                    not_playable.insert(track_id.clone());
+                }
+                tracks.entry(track_id.clone()).or_insert_with(|| Track {
                     spotify_id: track_id.clone(),
                     recco_id: None,
                     name: t.name.clone(),
@@ -163,31 +391,60 @@ pub async fn run_sync(
                     liveness: None,
                     loudness: None,
                     unavailable: false,
+                    unavailable_reason: None,
+                    unavailable_since: None,
                     first_seen: None,
                     last_seen: None,
                     updated: None,
                 });
-                merge_source(entry, &playlist.name);
+                entries.push(db::PlaylistEntry {
+                    spotify_id: track_id,
+                    position: position as i64,
+                    added_at: item.added_at,
+                });
+            }
+        }
+        playlist_entries.push((db::Playlist { track_count: entries.len() as i64, ..info }, Some(entries)));
+    }
+
+    // refetched tracks that are also in an unchanged source keep it in their sources
+    for (id, sources) in &kept_sources {
+        if let Some(track) = tracks.get_mut(id) {
+            for source in sources {
+                merge_source(track, source);
             }
         }
     }
 
-    println!("total unique tracks: {}", tracks.len());
+    progress.info(format!("total unique tracks: {}", tracks.len()));
+    if !kept_sources.is_empty() {
+        progress.info(format!("plus {} tracks from unchanged sources", kept_sources.keys().filter(|id| !tracks.contains_key(*id)).count()));
+    }
+
+    for id in &not_playable {
+        if let Some(track) = tracks.get_mut(id) {
+            track.unavailable = true;
+            track.unavailable_reason = Some("not_playable".to_string());
+        }
+    }
+    if !not_playable.is_empty() {
+        progress.info(format!("{} tracks not playable", not_playable.len()));
+    }
 
     // collect unique artist IDs and fetch genres
     let all_artist_ids: HashSet<String> = track_artist_ids.values()
         .flat_map(|ids| ids.iter().cloned())
         .collect();
-    println!("fetching genres for {} unique artists...", all_artist_ids.len());
+    progress.phase("genres", format!("fetching genres for {} unique artists...", all_artist_ids.len()));
     
     let mut artist_genres: HashMap<String, Vec<String>> = HashMap::new();
     let artist_ids_vec: Vec<String> = all_artist_ids.into_iter().collect();
     
     for (i, chunk) in artist_ids_vec.chunks(50).enumerate() {
         if (i + 1) % 10 == 0 || (i + 1) * 50 >= artist_ids_vec.len() {
-            println!("  artists {}/{}", ((i + 1) * 50).min(artist_ids_vec.len()), artist_ids_vec.len());
+            progress.count("artists", ((i + 1) * 50).min(artist_ids_vec.len()), artist_ids_vec.len());
         }
-        match spotify.get_artists_batch(&chunk.to_vec()).await {
+        match spotify.get_artists_batch(chunk).await {
             Ok(artists) => {
                 for artist in artists {
                     if !artist.genres.is_empty() {
@@ -196,11 +453,11 @@ pub async fn run_sync(
                 }
             }
             Err(e) => {
-                println!("  artist batch failed: {}", e);
+                progress.warn(format!("artist batch failed: {}", e));
             }
         }
     }
-    println!("  got genres for {} artists", artist_genres.len());
+    progress.info(format!("got genres for {} artists", artist_genres.len()));
 
     // map genres to tracks
     for (track_id, artist_ids) in &track_artist_ids {
@@ -221,96 +478,158 @@ pub async fn run_sync(
     }
 
     if dry_run {
-        println!("dry run - not saving to database");
+        progress.phase("finished", "dry run - not saving to database");
         return Ok(SyncResult { added: 0, updated: 0, unavailable: 0 });
     }
 
     // save new refresh token if provided
     if let Some(new_refresh) = token.refresh_token {
-        let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
-        db::set_config(&conn, "spotify_refresh_token", &new_refresh)
-            .map_err(|e| e.to_string())?;
+        pool::run_blocking(db_path, move |conn| {
+            db::set_config(conn, "spotify_refresh_token", &new_refresh).map_err(|e| e.to_string())
+        })
+        .await?;
     }
 
     // get list of tracks that need features (check db)
-    let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
-    let mut needs_features: Vec<String> = vec![];
-    
     if backfill {
-        // backfill mode: get ALL tracks from db missing features
-        println!("backfill mode: checking all tracks in db...");
-        let all_missing = db::get_tracks_missing_features(&conn).map_err(|e| e.to_string())?;
-        needs_features = all_missing;
+        progress.info("backfill mode: checking all tracks in db...");
+    }
+    // tracks kept from unchanged sources weren't refetched but are still due a retry after their cool-off
+    let synced_ids: Vec<String> = if backfill {
+        vec![]
     } else {
+        tracks.keys().chain(kept_sources.keys()).cloned().collect::<HashSet<_>>().into_iter().collect()
+    };
+    let needs_features = pool::run_blocking(db_path, move |conn| {
+        // tracks reccobeats didn't know or failed on are only retried after a cool-off
+        let due = db::get_tracks_missing_features(conn).map_err(|e| e.to_string())?;
+        if backfill {
+            // backfill mode: get ALL tracks from db missing features
+            return Ok(due);
+        }
         // normal mode: only check tracks from current sync
-        for (spotify_id, _) in &tracks {
-            let existing = db::get_track(&conn, spotify_id).map_err(|e| e.to_string())?;
-            if existing.as_ref().map(|t| t.tempo.is_none()).unwrap_or(true) {
-                needs_features.push(spotify_id.clone());
+        let due: HashSet<String> = due.into_iter().collect();
+        let mut needs_features: Vec<String> = vec![];
+        for spotify_id in synced_ids {
+            let existing = db::get_track(conn, &spotify_id).map_err(|e| e.to_string())?;
+            let needed = match existing {
+                Some(t) => t.tempo.is_none() && due.contains(&spotify_id),
+                None => true,
+            };
+            if needed {
+                needs_features.push(spotify_id);
             }
         }
-    }
-    drop(conn);
+        Ok(needs_features)
+    })
+    .await?;
 
     // fetch audio features from reccobeats (two-step: get recco_id, then features)
-    println!("fetching audio features for {} tracks...", needs_features.len());
-    let recco = ReccobeatsClient::new();
+    progress.phase("features", format!("fetching audio features for {} tracks...", needs_features.len()));
+    let recco = ReccobeatsClient::with_concurrency(concurrency).with_retry_log(progress.retry_log());
     let mut features_map: HashMap<String, crate::spotify::ReccoAudioFeatures> = HashMap::new();
     let mut recco_id_map: HashMap<String, String> = HashMap::new(); // spotify_id -> recco_id
+    let mut feature_status: HashMap<String, FeatureStatus> = HashMap::new();
 
-    // step 1: get recco track ids (batch of 50)
+    // step 1: get recco track ids (batches of 40, `concurrency` in flight)
     let total = needs_features.len();
-    println!("  step 1: looking up recco track ids...");
-    for (i, chunk) in needs_features.chunks(40).enumerate() {
-        let batch_num = (i + 1) * 40;
-        if batch_num % 400 == 0 || batch_num >= total {
-            println!("    lookup {}/{}", batch_num.min(total), total);
+    progress.info("step 1: looking up recco track ids...");
+    let mut lookups = JoinSet::new();
+    for chunk in needs_features.chunks(40) {
+        let recco = recco.clone();
+        let chunk = chunk.to_vec();
+        lookups.spawn(async move {
+            let result = recco.get_tracks_by_spotify_ids(&chunk).await;
+            (chunk, result)
+        });
+    }
+    let mut done = 0;
+    while let Some(joined) = lookups.join_next().await {
+        let (chunk, result) = joined.map_err(|e| e.to_string())?;
+        let n = chunk.len();
+        if (done + n) / 400 > done / 400 || done + n >= total {
+            progress.count("lookup", done + n, total);
         }
+        done += n;
 
-        match recco.get_tracks_by_spotify_ids(&chunk.to_vec()).await {
+        match result {
             Ok(tracks_info) => {
                 for info in tracks_info {
                     if let Some(spotify_id) = info.spotify_id() {
                         recco_id_map.insert(spotify_id, info.id);
                     }
                 }
+                for id in chunk {
+                    if !recco_id_map.contains_key(&id) {
+                        feature_status.insert(id, FeatureStatus::NotFound);
+                    }
+                }
             }
             Err(e) => {
-                println!("    lookup batch failed: {}", e);
+                progress.warn(format!("lookup batch failed: {}", e));
+                for id in chunk {
+                    feature_status.insert(id, FeatureStatus::Error);
+                }
             }
         }
     }
-    println!("  found {} tracks in reccobeats", recco_id_map.len());
+    progress.info(format!("found {} tracks in reccobeats", recco_id_map.len()));
 
     // step 2: get audio features for each recco track
-    println!("  step 2: fetching audio features...");
-    let recco_ids: Vec<_> = recco_id_map.iter().collect();
-    for (i, (spotify_id, recco_id)) in recco_ids.iter().enumerate() {
-        if (i + 1) % 100 == 0 {
-            println!("    features {}/{}", i + 1, recco_ids.len());
+    progress.info("step 2: fetching audio features...");
+    let mut fetches = JoinSet::new();
+    for (spotify_id, recco_id) in &recco_id_map {
+        let recco = recco.clone();
+        let spotify_id = spotify_id.clone();
+        let recco_id = recco_id.clone();
+        fetches.spawn(async move {
+            let result = recco.get_audio_features(&recco_id).await;
+            (spotify_id, result)
+        });
+    }
+    let total = recco_id_map.len();
+    let mut done = 0;
+    let mut failed = 0;
+    while let Some(joined) = fetches.join_next().await {
+        let (spotify_id, result) = joined.map_err(|e| e.to_string())?;
+        done += 1;
+        if done % 100 == 0 {
+            progress.count("features", done, total);
         }
 
-        match recco.get_audio_features(recco_id).await {
+        match result {
             Ok(Some(features)) => {
-                features_map.insert((*spotify_id).clone(), features);
+                feature_status.insert(spotify_id.clone(), FeatureStatus::Ok);
+                features_map.insert(spotify_id, features);
+            }
+            Ok(None) => {
+                feature_status.insert(spotify_id, FeatureStatus::NotFound);
+            }
+            Err(e) => {
+                failed += 1;
+                feature_status.insert(spotify_id.clone(), FeatureStatus::Error);
+                if failed <= 5 {
+                    progress.warn(format!("features failed for {}: {}", spotify_id, e));
+                }
             }
-            Ok(None) => {}
-            Err(_) => {}
         }
     }
-    println!("  got features for {} tracks", features_map.len());
+    progress.info(format!("got features for {} tracks ({} failed)", features_map.len(), failed));
 
     // now do all db writes synchronously
-    println!("saving to database...");
-    let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
-    let mut added = 0i64;
-    let mut updated = 0i64;
+    progress.phase("saving", "saving to database...");
+    // one transaction for the whole save, a crash or error leaves the library as it was.
+    // thousands of statements, so it runs on a blocking thread rather than an async worker
+    let still_owned = owned_ids.clone();
+    let (added, updated, removed, newly_unplayable) = pool::run_blocking(db_path, move |conn| {
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let mut added = 0i64;
+        let mut updated = 0i64;
 
-    // in backfill mode, update features for tracks not in current sync
-    if backfill {
+        // features for tracks that weren't refetched: kept from unchanged sources, or anything in backfill mode
         for (spotify_id, features) in &features_map {
             if !tracks.contains_key(spotify_id) {
-                if let Ok(Some(mut existing)) = db::get_track(&conn, spotify_id) {
+                if let Ok(Some(mut existing)) = db::get_track(&tx, spotify_id) {
                     existing.tempo = features.tempo;
                     existing.key = features.key;
                     existing.mode = features.mode;
@@ -325,61 +644,128 @@ pub async fn run_sync(
                     if let Some(recco_id) = recco_id_map.get(spotify_id) {
                         existing.recco_id = Some(recco_id.clone());
                     }
-                    let _ = db::upsert_track(&conn, &existing);
+                    let _ = db::upsert_track(&tx, &existing);
                     updated += 1;
                 }
             }
         }
-    }
 
-    for (spotify_id, mut track) in tracks {
-        // store recco_id if we found it
-        if let Some(recco_id) = recco_id_map.get(&spotify_id) {
-            track.recco_id = Some(recco_id.clone());
+        // tracks that were playable last time and aren't now, already-flagged ones don't count again
+        let mut newly_unplayable = 0i64;
+        let seen: HashSet<String> = tracks.keys().cloned().collect();
+        for (spotify_id, mut track) in tracks {
+            let existing = db::get_track(&tx, &spotify_id).map_err(|e| e.to_string())?;
+            if track.unavailable && existing.as_ref().is_some_and(|ex| !ex.unavailable) {
+                newly_unplayable += 1;
+            }
+
+            // store recco_id if we found it
+            if let Some(recco_id) = recco_id_map.get(&spotify_id) {
+                track.recco_id = Some(recco_id.clone());
+            }
+
+            // apply features if we fetched them
+            if let Some(features) = features_map.get(&spotify_id) {
+                track.tempo = features.tempo;
+                track.key = features.key;
+                track.mode = features.mode;
+                track.danceability = features.danceability;
+                track.energy = features.energy;
+                track.valence = features.valence;
+                track.acousticness = features.acousticness;
+                track.instrumentalness = features.instrumentalness;
+                track.speechiness = features.speechiness;
+                track.liveness = features.liveness;
+                track.loudness = features.loudness;
+            } else {
+                // preserve existing features if track already in db
+                if let Some(ex) = existing {
+                    track.recco_id = ex.recco_id;
+                    track.tempo = ex.tempo;
+                    track.key = ex.key;
+                    track.mode = ex.mode;
+                    track.danceability = ex.danceability;
+                    track.energy = ex.energy;
+                    track.valence = ex.valence;
+                    track.acousticness = ex.acousticness;
+                    track.instrumentalness = ex.instrumentalness;
+                    track.speechiness = ex.speechiness;
+                    track.liveness = ex.liveness;
+                    track.loudness = ex.loudness;
+                }
+            }
+
+            let is_new = db::upsert_track(&tx, &track).map_err(|e| e.to_string())?;
+            if is_new {
+                added += 1;
+            } else {
+                updated += 1;
+            }
         }
 
-        // apply features if we fetched them
-        if let Some(features) = features_map.get(&spotify_id) {
-            track.tempo = features.tempo;
-            track.key = features.key;
-            track.mode = features.mode;
-            track.danceability = features.danceability;
-            track.energy = features.energy;
-            track.valence = features.valence;
-            track.acousticness = features.acousticness;
-            track.instrumentalness = features.instrumentalness;
-            track.speechiness = features.speechiness;
-            track.liveness = features.liveness;
-            track.loudness = features.loudness;
-        } else {
-            // preserve existing features if track already in db
-            if let Ok(Some(ex)) = db::get_track(&conn, &spotify_id) {
-                track.recco_id = ex.recco_id;
-                track.tempo = ex.tempo;
-                track.key = ex.key;
-                track.mode = ex.mode;
-                track.danceability = ex.danceability;
-                track.energy = ex.energy;
-                track.valence = ex.valence;
-                track.acousticness = ex.acousticness;
-                track.instrumentalness = ex.instrumentalness;
-                track.speechiness = ex.speechiness;
-                track.liveness = ex.liveness;
-                track.loudness = ex.loudness;
+        // unliked tracks that weren't refetched (e.g. still in an unchanged playlist) would otherwise
+        // keep "liked" and the incremental check would never match spotify's total again
+        if let Some(liked_now) = &liked_now {
+            for spotify_id in db::get_source_track_ids(&tx, "liked").map_err(|e| e.to_string())? {
+                if !liked_now.contains(&spotify_id) {
+                    db::remove_source(&tx, &spotify_id, "liked").map_err(|e| e.to_string())?;
+                }
             }
         }
 
-        let is_new = db::upsert_track(&conn, &track).map_err(|e| e.to_string())?;
-        if is_new {
-            added += 1;
-        } else {
-            updated += 1;
+        for (spotify_id, status) in &feature_status {
+            db::set_feature_status(&tx, spotify_id, *status).map_err(|e| e.to_string())?;
+        }
+
+        // playlist membership, keyed by playlist id so renames keep their history
+        for (playlist, entries) in &playlist_entries {
+            db::upsert_playlist(&tx, playlist).map_err(|e| e.to_string())?;
+            if let Some(entries) = entries {
+                db::set_playlist_tracks(&tx, &playlist.id, entries).map_err(|e| e.to_string())?;
+            }
+        }
+        if let Some(newest) = liked_newest {
+            db::set_config(&tx, "liked_newest_added_at", &newest).map_err(|e| e.to_string())?;
+        }
+        for existing in db::get_playlists(&tx).map_err(|e| e.to_string())? {
+            if !still_owned.contains(&existing.id) {
+                db::delete_playlist(&tx, &existing.id).map_err(|e| e.to_string())?;
+            }
         }
+
+        // anything still marked available in the db that we didn't see is gone from the library
+        let mut removed = 0i64;
+        if complete {
+            let known = db::get_available_track_ids(&tx).map_err(|e| e.to_string())?;
+            for spotify_id in known {
+                if !seen.contains(&spotify_id)
+                    && !kept_sources.contains_key(&spotify_id)
+                    && db::mark_unavailable(&tx, &spotify_id, "removed").map_err(|e| e.to_string())?
+                {
+                    removed += 1;
+                }
+            }
+        }
+
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok((added, updated, removed, newly_unplayable))
+    })
+    .await?;
+    if !complete {
+        progress.warn("some sources failed to fetch - not marking missing tracks as removed");
+    }
+    // the library is already saved, a publishing problem shouldn't turn the sync into a failure
+    if let Err(e) = crate::playlist::publish_smart_playlists(db_path, &spotify, &user_id, &owned_ids, progress).await {
+        progress.warn(format!("smart playlists not published: {}", e));
     }
 
-    println!("sync complete: {} added, {} updated", added, updated);
+    let unavailable = removed + newly_unplayable;
+    progress.phase(
+        "finished",
+        format!("sync complete: {} added, {} updated, {} unavailable ({} removed)", added, updated, unavailable, removed),
+    );
 
-    Ok(SyncResult { added, updated, unavailable: 0 })
+    Ok(SyncResult { added, updated, unavailable })
 }
 
 fn merge_source(track: &mut Track, source: &str) {
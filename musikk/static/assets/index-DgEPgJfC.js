@@ -1,40 +0,0 @@
-(function(){const B=document.createElement("link").relList;if(B&&B.supports&&B.supports("modulepreload"))return;for(const W of document.querySelectorAll('link[rel="modulepreload"]'))Ce(W);new MutationObserver(W=>{for(const G of W)if(G.type==="childList")for(const ce of G.addedNodes)ce.tagName==="LINK"&&ce.rel==="modulepreload"&&Ce(ce)}).observe(document,{childList:!0,subtree:!0});function v(W){const G={};return W.integrity&&(G.integrity=W.integrity),W.referrerPolicy&&(G.referrerPolicy=W.referrerPolicy),W.crossOrigin==="use-credentials"?G.credentials="include":W.crossOrigin==="anonymous"?G.credentials="omit":G.credentials="same-origin",G}function Ce(W){if(W.ep)return;W.ep=!0;const G=v(W);fetch(W.href,G)}})();var Uu={exports:{}},Fr={},Au={exports:{}},Q={};/**
- * @license React
- * react.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var za;function Mf(){if(za)return Q;za=1;var L=Symbol.for("react.element"),B=Symbol.for("react.portal"),v=Symbol.for("react.fragment"),Ce=Symbol.for("react.strict_mode"),W=Symbol.for("react.profiler"),G=Symbol.for("react.provider"),ce=Symbol.for("react.context"),se=Symbol.for("react.forward_ref"),F=Symbol.for("react.suspense"),K=Symbol.for("react.memo"),U=Symbol.for("react.lazy"),te=Symbol.iterator;function A(d){return d===null||typeof d!="object"?null:(d=te&&d[te]||d["@@iterator"],typeof d=="function"?d:null)}var me={isMounted:function(){return!1},enqueueForceUpdate:function(){},enqueueReplaceState:function(){},enqueueSetState:function(){}},Re=Object.assign,ie={};function ne(d,y,$){this.props=d,this.context=y,this.refs=ie,this.updater=$||me}ne.prototype.isReactComponent={},ne.prototype.setState=function(d,y){if(typeof d!="object"&&typeof d!="function"&&d!=null)throw Error("setState(...): takes an object of state variables to update or a function which returns an object of state variables.");this.updater.enqueueSetState(this,d,y,"setState")},ne.prototype.forceUpdate=function(d){this.updater.enqueueForceUpdate(this,d,"forceUpdate")};function dt(){}dt.prototype=ne.prototype;function I(d,y,$){this.props=d,this.context=y,this.refs=ie,this.updater=$||me}var Ke=I.prototype=new dt;Ke.constructor=I,Re(Ke,ne.prototype),Ke.isPureReactComponent=!0;var ee=Array.isArray,et=Object.prototype.hasOwnProperty,T={current:null},Pe={key:!0,ref:!0,__self:!0,__source:!0};function we(d,y,$){var H,Y={},X=null,re=null;if(y!=null)for(H in y.ref!==void 0&&(re=y.ref),y.key!==void 0&&(X=""+y.key),y)et.call(y,H)&&!Pe.hasOwnProperty(H)&&(Y[H]=y[H]);var Z=arguments.length-2;if(Z===1)Y.children=$;else if(1<Z){for(var oe=Array(Z),Ue=0;Ue<Z;Ue++)oe[Ue]=arguments[Ue+2];Y.children=oe}if(d&&d.defaultProps)for(H in Z=d.defaultProps,Z)Y[H]===void 0&&(Y[H]=Z[H]);return{$$typeof:L,type:d,key:X,ref:re,props:Y,_owner:T.current}}function Ie(d,y){return{$$typeof:L,type:d.type,key:y,ref:d.ref,props:d.props,_owner:d._owner}}function it(d){return typeof d=="object"&&d!==null&&d.$$typeof===L}function xt(d){var y={"=":"=0",":":"=2"};return"$"+d.replace(/[=:]/g,function($){return y[$]})}var De=/\/+/g;function He(d,y){return typeof d=="object"&&d!==null&&d.key!=null?xt(""+d.key):y.toString(36)}function Me(d,y,$,H,Y){var X=typeof d;(X==="undefined"||X==="boolean")&&(d=null);var re=!1;if(d===null)re=!0;else switch(X){case"string":case"number":re=!0;break;case"object":switch(d.$$typeof){case L:case B:re=!0}}if(re)return re=d,Y=Y(re),d=H===""?"."+He(re,0):H,ee(Y)?($="",d!=null&&($=d.replace(De,"$&/")+"/"),Me(Y,y,$,"",function(Ue){return Ue})):Y!=null&&(it(Y)&&(Y=Ie(Y,$+(!Y.key||re&&re.key===Y.key?"":(""+Y.key).replace(De,"$&/")+"/")+d)),y.push(Y)),1;if(re=0,H=H===""?".":H+":",ee(d))for(var Z=0;Z<d.length;Z++){X=d[Z];var oe=H+He(X,Z);re+=Me(X,y,$,oe,Y)}else if(oe=A(d),typeof oe=="function")for(d=oe.call(d),Z=0;!(X=d.next()).done;)X=X.value,oe=H+He(X,Z++),re+=Me(X,y,$,oe,Y);else if(X==="object")throw y=String(d),Error("Objects are not valid as a React child (found: "+(y==="[object Object]"?"object with keys {"+Object.keys(d).join(", ")+"}":y)+"). If you meant to render a collection of children, use an array instead.");return re}function Ye(d,y,$){if(d==null)return d;var H=[],Y=0;return Me(d,H,"","",function(X){return y.call($,X,Y++)}),H}function Ne(d){if(d._status===-1){var y=d._result;y=y(),y.then(function($){(d._status===0||d._status===-1)&&(d._status=1,d._result=$)},function($){(d._status===0||d._status===-1)&&(d._status=2,d._result=$)}),d._status===-1&&(d._status=0,d._result=y)}if(d._status===1)return d._result.default;throw d._result}var ue={current:null},C={transition:null},R={ReactCurrentDispatcher:ue,ReactCurrentBatchConfig:C,ReactCurrentOwner:T};function N(){throw Error("act(...) is not supported in production builds of React.")}return Q.Children={map:Ye,forEach:function(d,y,$){Ye(d,function(){y.apply(this,arguments)},$)},count:function(d){var y=0;return Ye(d,function(){y++}),y},toArray:function(d){return Ye(d,function(y){return y})||[]},only:function(d){if(!it(d))throw Error("React.Children.only expected to receive a single React element child.");return d}},Q.Component=ne,Q.Fragment=v,Q.Profiler=W,Q.PureComponent=I,Q.StrictMode=Ce,Q.Suspense=F,Q.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=R,Q.act=N,Q.cloneElement=function(d,y,$){if(d==null)throw Error("React.cloneElement(...): The argument must be a React element, but you passed "+d+".");var H=Re({},d.props),Y=d.key,X=d.ref,re=d._owner;if(y!=null){if(y.ref!==void 0&&(X=y.ref,re=T.current),y.key!==void 0&&(Y=""+y.key),d.type&&d.type.defaultProps)var Z=d.type.defaultProps;for(oe in y)et.call(y,oe)&&!Pe.hasOwnProperty(oe)&&(H[oe]=y[oe]===void 0&&Z!==void 0?Z[oe]:y[oe])}var oe=arguments.length-2;if(oe===1)H.children=$;else if(1<oe){Z=Array(oe);for(var Ue=0;Ue<oe;Ue++)Z[Ue]=arguments[Ue+2];H.children=Z}return{$$typeof:L,type:d.type,key:Y,ref:X,props:H,_owner:re}},Q.createContext=function(d){return d={$$typeof:ce,_currentValue:d,_currentValue2:d,_threadCount:0,Provider:null,Consumer:null,_defaultValue:null,_globalName:null},d.Provider={$$typeof:G,_context:d},d.Consumer=d},Q.createElement=we,Q.createFactory=function(d){var y=we.bind(null,d);return y.type=d,y},Q.createRef=function(){return{current:null}},Q.forwardRef=function(d){return{$$typeof:se,render:d}},Q.isValidElement=it,Q.lazy=function(d){return{$$typeof:U,_payload:{_status:-1,_result:d},_init:Ne}},Q.memo=function(d,y){return{$$typeof:K,type:d,compare:y===void 0?null:y}},Q.startTransition=function(d){var y=C.transition;C.transition={};try{d()}finally{C.transition=y}},Q.unstable_act=N,Q.useCallback=function(d,y){return ue.current.useCallback(d,y)},Q.useContext=function(d){return ue.current.useContext(d)},Q.useDebugValue=function(){},Q.useDeferredValue=function(d){return ue.current.useDeferredValue(d)},Q.useEffect=function(d,y){return ue.current.useEffect(d,y)},Q.useId=function(){return ue.current.useId()},Q.useImperativeHandle=function(d,y,$){return ue.current.useImperativeHandle(d,y,$)},Q.useInsertionEffect=function(d,y){return ue.current.useInsertionEffect(d,y)},Q.useLayoutEffect=function(d,y){return ue.current.useLayoutEffect(d,y)},Q.useMemo=function(d,y){return ue.current.useMemo(d,y)},Q.useReducer=function(d,y,$){return ue.current.useReducer(d,y,$)},Q.useRef=function(d){return ue.current.useRef(d)},Q.useState=function(d){return ue.current.useState(d)},Q.useSyncExternalStore=function(d,y,$){return ue.current.useSyncExternalStore(d,y,$)},Q.useTransition=function(){return ue.current.useTransition()},Q.version="18.3.1",Q}var La;function Wu(){return La||(La=1,Au.exports=Mf()),Au.exports}/**
- * @license React
- * react-jsx-runtime.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Ta;function Of(){if(Ta)return Fr;Ta=1;var L=Wu(),B=Symbol.for("react.element"),v=Symbol.for("react.fragment"),Ce=Object.prototype.hasOwnProperty,W=L.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED.ReactCurrentOwner,G={key:!0,ref:!0,__self:!0,__source:!0};function ce(se,F,K){var U,te={},A=null,me=null;K!==void 0&&(A=""+K),F.key!==void 0&&(A=""+F.key),F.ref!==void 0&&(me=F.ref);for(U in F)Ce.call(F,U)&&!G.hasOwnProperty(U)&&(te[U]=F[U]);if(se&&se.defaultProps)for(U in F=se.defaultProps,F)te[U]===void 0&&(te[U]=F[U]);return{$$typeof:B,type:se,key:A,ref:me,props:te,_owner:W.current}}return Fr.Fragment=v,Fr.jsx=ce,Fr.jsxs=ce,Fr}var Ra;function Ff(){return Ra||(Ra=1,Uu.exports=Of()),Uu.exports}var f=Ff(),b=Wu(),Gl={},Vu={exports:{}},be={},Bu={exports:{}},$u={};/**
- * @license React
- * scheduler.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Ma;function If(){return Ma||(Ma=1,(function(L){function B(C,R){var N=C.length;C.push(R);e:for(;0<N;){var d=N-1>>>1,y=C[d];if(0<W(y,R))C[d]=R,C[N]=y,N=d;else break e}}function v(C){return C.length===0?null:C[0]}function Ce(C){if(C.length===0)return null;var R=C[0],N=C.pop();if(N!==R){C[0]=N;e:for(var d=0,y=C.length,$=y>>>1;d<$;){var H=2*(d+1)-1,Y=C[H],X=H+1,re=C[X];if(0>W(Y,N))X<y&&0>W(re,Y)?(C[d]=re,C[X]=N,d=X):(C[d]=Y,C[H]=N,d=H);else if(X<y&&0>W(re,N))C[d]=re,C[X]=N,d=X;else break e}}return R}function W(C,R){var N=C.sortIndex-R.sortIndex;return N!==0?N:C.id-R.id}if(typeof performance=="object"&&typeof performance.now=="function"){var G=performance;L.unstable_now=function(){return G.now()}}else{var ce=Date,se=ce.now();L.unstable_now=function(){return ce.now()-se}}var F=[],K=[],U=1,te=null,A=3,me=!1,Re=!1,ie=!1,ne=typeof setTimeout=="function"?setTimeout:null,dt=typeof clearTimeout=="function"?clearTimeout:null,I=typeof setImmediate<"u"?setImmediate:null;typeof navigator<"u"&&navigator.scheduling!==void 0&&navigator.scheduling.isInputPending!==void 0&&navigator.scheduling.isInputPending.bind(navigator.scheduling);function Ke(C){for(var R=v(K);R!==null;){if(R.callback===null)Ce(K);else if(R.startTime<=C)Ce(K),R.sortIndex=R.expirationTime,B(F,R);else break;R=v(K)}}function ee(C){if(ie=!1,Ke(C),!Re)if(v(F)!==null)Re=!0,Ne(et);else{var R=v(K);R!==null&&ue(ee,R.startTime-C)}}function et(C,R){Re=!1,ie&&(ie=!1,dt(we),we=-1),me=!0;var N=A;try{for(Ke(R),te=v(F);te!==null&&(!(te.expirationTime>R)||C&&!xt());){var d=te.callback;if(typeof d=="function"){te.callback=null,A=te.priorityLevel;var y=d(te.expirationTime<=R);R=L.unstable_now(),typeof y=="function"?te.callback=y:te===v(F)&&Ce(F),Ke(R)}else Ce(F);te=v(F)}if(te!==null)var $=!0;else{var H=v(K);H!==null&&ue(ee,H.startTime-R),$=!1}return $}finally{te=null,A=N,me=!1}}var T=!1,Pe=null,we=-1,Ie=5,it=-1;function xt(){return!(L.unstable_now()-it<Ie)}function De(){if(Pe!==null){var C=L.unstable_now();it=C;var R=!0;try{R=Pe(!0,C)}finally{R?He():(T=!1,Pe=null)}}else T=!1}var He;if(typeof I=="function")He=function(){I(De)};else if(typeof MessageChannel<"u"){var Me=new MessageChannel,Ye=Me.port2;Me.port1.onmessage=De,He=function(){Ye.postMessage(null)}}else He=function(){ne(De,0)};function Ne(C){Pe=C,T||(T=!0,He())}function ue(C,R){we=ne(function(){C(L.unstable_now())},R)}L.unstable_IdlePriority=5,L.unstable_ImmediatePriority=1,L.unstable_LowPriority=4,L.unstable_NormalPriority=3,L.unstable_Profiling=null,L.unstable_UserBlockingPriority=2,L.unstable_cancelCallback=function(C){C.callback=null},L.unstable_continueExecution=function(){Re||me||(Re=!0,Ne(et))},L.unstable_forceFrameRate=function(C){0>C||125<C?console.error("forceFrameRate takes a positive int between 0 and 125, forcing frame rates higher than 125 fps is not supported"):Ie=0<C?Math.floor(1e3/C):5},L.unstable_getCurrentPriorityLevel=function(){return A},L.unstable_getFirstCallbackNode=function(){return v(F)},L.unstable_next=function(C){switch(A){case 1:case 2:case 3:var R=3;break;default:R=A}var N=A;A=R;try{return C()}finally{A=N}},L.unstable_pauseExecution=function(){},L.unstable_requestPaint=function(){},L.unstable_runWithPriority=function(C,R){switch(C){case 1:case 2:case 3:case 4:case 5:break;default:C=3}var N=A;A=C;try{return R()}finally{A=N}},L.unstable_scheduleCallback=function(C,R,N){var d=L.unstable_now();switch(typeof N=="object"&&N!==null?(N=N.delay,N=typeof N=="number"&&0<N?d+N:d):N=d,C){case 1:var y=-1;break;case 2:y=250;break;case 5:y=1073741823;break;case 4:y=1e4;break;default:y=5e3}return y=N+y,C={id:U++,callback:R,priorityLevel:C,startTime:N,expirationTime:y,sortIndex:-1},N>d?(C.sortIndex=N,B(K,C),v(F)===null&&C===v(K)&&(ie?(dt(we),we=-1):ie=!0,ue(ee,N-d))):(C.sortIndex=y,B(F,C),Re||me||(Re=!0,Ne(et))),C},L.unstable_shouldYield=xt,L.unstable_wrapCallback=function(C){var R=A;return function(){var N=A;A=R;try{return C.apply(this,arguments)}finally{A=N}}}})($u)),$u}var Oa;function Df(){return Oa||(Oa=1,Bu.exports=If()),Bu.exports}/**
- * @license React
- * react-dom.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Fa;function Uf(){if(Fa)return be;Fa=1;var L=Wu(),B=Df();function v(e){for(var t="https://reactjs.org/docs/error-decoder.html?invariant="+e,n=1;n<arguments.length;n++)t+="&args[]="+encodeURIComponent(arguments[n]);return"Minified React error #"+e+"; visit "+t+" for the full message or use the non-minified dev environment for full errors and additional helpful warnings."}var Ce=new Set,W={};function G(e,t){ce(e,t),ce(e+"Capture",t)}function ce(e,t){for(W[e]=t,e=0;e<t.length;e++)Ce.add(t[e])}var se=!(typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"),F=Object.prototype.hasOwnProperty,K=/^[:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD][:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD\-.0-9\u00B7\u0300-\u036F\u203F-\u2040]*$/,U={},te={};function A(e){return F.call(te,e)?!0:F.call(U,e)?!1:K.test(e)?te[e]=!0:(U[e]=!0,!1)}function me(e,t,n,r){if(n!==null&&n.type===0)return!1;switch(typeof t){case"function":case"symbol":return!0;case"boolean":return r?!1:n!==null?!n.acceptsBooleans:(e=e.toLowerCase().slice(0,5),e!=="data-"&&e!=="aria-");default:return!1}}function Re(e,t,n,r){if(t===null||typeof t>"u"||me(e,t,n,r))return!0;if(r)return!1;if(n!==null)switch(n.type){case 3:return!t;case 4:return t===!1;case 5:return isNaN(t);case 6:return isNaN(t)||1>t}return!1}function ie(e,t,n,r,l,i,u){this.acceptsBooleans=t===2||t===3||t===4,this.attributeName=r,this.attributeNamespace=l,this.mustUseProperty=n,this.propertyName=e,this.type=t,this.sanitizeURL=i,this.removeEmptyString=u}var ne={};"children dangerouslySetInnerHTML defaultValue defaultChecked innerHTML suppressContentEditableWarning suppressHydrationWarning style".split(" ").forEach(function(e){ne[e]=new ie(e,0,!1,e,null,!1,!1)}),[["acceptCharset","accept-charset"],["className","class"],["htmlFor","for"],["httpEquiv","http-equiv"]].forEach(function(e){var t=e[0];ne[t]=new ie(t,1,!1,e[1],null,!1,!1)}),["contentEditable","draggable","spellCheck","value"].forEach(function(e){ne[e]=new ie(e,2,!1,e.toLowerCase(),null,!1,!1)}),["autoReverse","externalResourcesRequired","focusable","preserveAlpha"].forEach(function(e){ne[e]=new ie(e,2,!1,e,null,!1,!1)}),"allowFullScreen async autoFocus autoPlay controls default defer disabled disablePictureInPicture disableRemotePlayback formNoValidate hidden loop noModule noValidate open playsInline readOnly required reversed scoped seamless itemScope".split(" ").forEach(function(e){ne[e]=new ie(e,3,!1,e.toLowerCase(),null,!1,!1)}),["checked","multiple","muted","selected"].forEach(function(e){ne[e]=new ie(e,3,!0,e,null,!1,!1)}),["capture","download"].forEach(function(e){ne[e]=new ie(e,4,!1,e,null,!1,!1)}),["cols","rows","size","span"].forEach(function(e){ne[e]=new ie(e,6,!1,e,null,!1,!1)}),["rowSpan","start"].forEach(function(e){ne[e]=new ie(e,5,!1,e.toLowerCase(),null,!1,!1)});var dt=/[\-:]([a-z])/g;function I(e){return e[1].toUpperCase()}"accent-height alignment-baseline arabic-form baseline-shift cap-height clip-path clip-rule color-interpolation color-interpolation-filters color-profile color-rendering dominant-baseline enable-background fill-opacity fill-rule flood-color flood-opacity font-family font-size font-size-adjust font-stretch font-style font-variant font-weight glyph-name glyph-orientation-horizontal glyph-orientation-vertical horiz-adv-x horiz-origin-x image-rendering letter-spacing lighting-color marker-end marker-mid marker-start overline-position overline-thickness paint-order panose-1 pointer-events rendering-intent shape-rendering stop-color stop-opacity strikethrough-position strikethrough-thickness stroke-dasharray stroke-dashoffset stroke-linecap stroke-linejoin stroke-miterlimit stroke-opacity stroke-width text-anchor text-decoration text-rendering underline-position underline-thickness unicode-bidi unicode-range units-per-em v-alphabetic v-hanging v-ideographic v-mathematical vector-effect vert-adv-y vert-origin-x vert-origin-y word-spacing writing-mode xmlns:xlink x-height".split(" ").forEach(function(e){var t=e.replace(dt,I);ne[t]=new ie(t,1,!1,e,null,!1,!1)}),"xlink:actuate xlink:arcrole xlink:role xlink:show xlink:title xlink:type".split(" ").forEach(function(e){var t=e.replace(dt,I);ne[t]=new ie(t,1,!1,e,"http://www.w3.org/1999/xlink",!1,!1)}),["xml:base","xml:lang","xml:space"].forEach(function(e){var t=e.replace(dt,I);ne[t]=new ie(t,1,!1,e,"http://www.w3.org/XML/1998/namespace",!1,!1)}),["tabIndex","crossOrigin"].forEach(function(e){ne[e]=new ie(e,1,!1,e.toLowerCase(),null,!1,!1)}),ne.xlinkHref=new ie("xlinkHref",1,!1,"xlink:href","http://www.w3.org/1999/xlink",!0,!1),["src","href","action","formAction"].forEach(function(e){ne[e]=new ie(e,1,!1,e.toLowerCase(),null,!0,!0)});function Ke(e,t,n,r){var l=ne.hasOwnProperty(t)?ne[t]:null;(l!==null?l.type!==0:r||!(2<t.length)||t[0]!=="o"&&t[0]!=="O"||t[1]!=="n"&&t[1]!=="N")&&(Re(t,n,l,r)&&(n=null),r||l===null?A(t)&&(n===null?e.removeAttribute(t):e.setAttribute(t,""+n)):l.mustUseProperty?e[l.propertyName]=n===null?l.type===3?!1:"":n:(t=l.attributeName,r=l.attributeNamespace,n===null?e.removeAttribute(t):(l=l.type,n=l===3||l===4&&n===!0?"":""+n,r?e.setAttributeNS(r,t,n):e.setAttribute(t,n))))}var ee=L.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED,et=Symbol.for("react.element"),T=Symbol.for("react.portal"),Pe=Symbol.for("react.fragment"),we=Symbol.for("react.strict_mode"),Ie=Symbol.for("react.profiler"),it=Symbol.for("react.provider"),xt=Symbol.for("react.context"),De=Symbol.for("react.forward_ref"),He=Symbol.for("react.suspense"),Me=Symbol.for("react.suspense_list"),Ye=Symbol.for("react.memo"),Ne=Symbol.for("react.lazy"),ue=Symbol.for("react.offscreen"),C=Symbol.iterator;function R(e){return e===null||typeof e!="object"?null:(e=C&&e[C]||e["@@iterator"],typeof e=="function"?e:null)}var N=Object.assign,d;function y(e){if(d===void 0)try{throw Error()}catch(n){var t=n.stack.trim().match(/\n( *(at )?)/);d=t&&t[1]||""}return`
-`+d+e}var $=!1;function H(e,t){if(!e||$)return"";$=!0;var n=Error.prepareStackTrace;Error.prepareStackTrace=void 0;try{if(t)if(t=function(){throw Error()},Object.defineProperty(t.prototype,"props",{set:function(){throw Error()}}),typeof Reflect=="object"&&Reflect.construct){try{Reflect.construct(t,[])}catch(h){var r=h}Reflect.construct(e,[],t)}else{try{t.call()}catch(h){r=h}e.call(t.prototype)}else{try{throw Error()}catch(h){r=h}e()}}catch(h){if(h&&r&&typeof h.stack=="string"){for(var l=h.stack.split(`
-`),i=r.stack.split(`
-`),u=l.length-1,o=i.length-1;1<=u&&0<=o&&l[u]!==i[o];)o--;for(;1<=u&&0<=o;u--,o--)if(l[u]!==i[o]){if(u!==1||o!==1)do if(u--,o--,0>o||l[u]!==i[o]){var s=`
-`+l[u].replace(" at new "," at ");return e.displayName&&s.includes("<anonymous>")&&(s=s.replace("<anonymous>",e.displayName)),s}while(1<=u&&0<=o);break}}}finally{$=!1,Error.prepareStackTrace=n}return(e=e?e.displayName||e.name:"")?y(e):""}function Y(e){switch(e.tag){case 5:return y(e.type);case 16:return y("Lazy");case 13:return y("Suspense");case 19:return y("SuspenseList");case 0:case 2:case 15:return e=H(e.type,!1),e;case 11:return e=H(e.type.render,!1),e;case 1:return e=H(e.type,!0),e;default:return""}}function X(e){if(e==null)return null;if(typeof e=="function")return e.displayName||e.name||null;if(typeof e=="string")return e;switch(e){case Pe:return"Fragment";case T:return"Portal";case Ie:return"Profiler";case we:return"StrictMode";case He:return"Suspense";case Me:return"SuspenseList"}if(typeof e=="object")switch(e.$$typeof){case xt:return(e.displayName||"Context")+".Consumer";case it:return(e._context.displayName||"Context")+".Provider";case De:var t=e.render;return e=e.displayName,e||(e=t.displayName||t.name||"",e=e!==""?"ForwardRef("+e+")":"ForwardRef"),e;case Ye:return t=e.displayName||null,t!==null?t:X(e.type)||"Memo";case Ne:t=e._payload,e=e._init;try{return X(e(t))}catch{}}return null}function re(e){var t=e.type;switch(e.tag){case 24:return"Cache";case 9:return(t.displayName||"Context")+".Consumer";case 10:return(t._context.displayName||"Context")+".Provider";case 18:return"DehydratedFragment";case 11:return e=t.render,e=e.displayName||e.name||"",t.displayName||(e!==""?"ForwardRef("+e+")":"ForwardRef");case 7:return"Fragment";case 5:return t;case 4:return"Portal";case 3:return"Root";case 6:return"Text";case 16:return X(t);case 8:return t===we?"StrictMode":"Mode";case 22:return"Offscreen";case 12:return"Profiler";case 21:return"Scope";case 13:return"Suspense";case 19:return"SuspenseList";case 25:return"TracingMarker";case 1:case 0:case 17:case 2:case 14:case 15:if(typeof t=="function")return t.displayName||t.name||null;if(typeof t=="string")return t}return null}function Z(e){switch(typeof e){case"boolean":case"number":case"string":case"undefined":return e;case"object":return e;default:return""}}function oe(e){var t=e.type;return(e=e.nodeName)&&e.toLowerCase()==="input"&&(t==="checkbox"||t==="radio")}function Ue(e){var t=oe(e)?"checked":"value",n=Object.getOwnPropertyDescriptor(e.constructor.prototype,t),r=""+e[t];if(!e.hasOwnProperty(t)&&typeof n<"u"&&typeof n.get=="function"&&typeof n.set=="function"){var l=n.get,i=n.set;return Object.defineProperty(e,t,{configurable:!0,get:function(){return l.call(this)},set:function(u){r=""+u,i.call(this,u)}}),Object.defineProperty(e,t,{enumerable:n.enumerable}),{getValue:function(){return r},setValue:function(u){r=""+u},stopTracking:function(){e._valueTracker=null,delete e[t]}}}}function En(e){e._valueTracker||(e._valueTracker=Ue(e))}function ye(e){if(!e)return!1;var t=e._valueTracker;if(!t)return!0;var n=t.getValue(),r="";return e&&(r=oe(e)?e.checked?"true":"false":e.value),e=r,e!==n?(t.setValue(e),!0):!1}function Ct(e){if(e=e||(typeof document<"u"?document:void 0),typeof e>"u")return null;try{return e.activeElement||e.body}catch{return e.body}}function Nn(e,t){var n=t.checked;return N({},t,{defaultChecked:void 0,defaultValue:void 0,value:void 0,checked:n??e._wrapperState.initialChecked})}function Dr(e,t){var n=t.defaultValue==null?"":t.defaultValue,r=t.checked!=null?t.checked:t.defaultChecked;n=Z(t.value!=null?t.value:n),e._wrapperState={initialChecked:r,initialValue:n,controlled:t.type==="checkbox"||t.type==="radio"?t.checked!=null:t.value!=null}}function Ur(e,t){t=t.checked,t!=null&&Ke(e,"checked",t,!1)}function Bt(e,t){Ur(e,t);var n=Z(t.value),r=t.type;if(n!=null)r==="number"?(n===0&&e.value===""||e.value!=n)&&(e.value=""+n):e.value!==""+n&&(e.value=""+n);else if(r==="submit"||r==="reset"){e.removeAttribute("value");return}t.hasOwnProperty("value")?Tt(e,t.type,n):t.hasOwnProperty("defaultValue")&&Tt(e,t.type,Z(t.defaultValue)),t.checked==null&&t.defaultChecked!=null&&(e.defaultChecked=!!t.defaultChecked)}function Et(e,t,n){if(t.hasOwnProperty("value")||t.hasOwnProperty("defaultValue")){var r=t.type;if(!(r!=="submit"&&r!=="reset"||t.value!==void 0&&t.value!==null))return;t=""+e._wrapperState.initialValue,n||t===e.value||(e.value=t),e.defaultValue=t}n=e.name,n!==""&&(e.name=""),e.defaultChecked=!!e._wrapperState.initialChecked,n!==""&&(e.name=n)}function Tt(e,t,n){(t!=="number"||Ct(e.ownerDocument)!==e)&&(n==null?e.defaultValue=""+e._wrapperState.initialValue:e.defaultValue!==""+n&&(e.defaultValue=""+n))}var pt=Array.isArray;function Nt(e,t,n,r){if(e=e.options,t){t={};for(var l=0;l<n.length;l++)t["$"+n[l]]=!0;for(n=0;n<e.length;n++)l=t.hasOwnProperty("$"+e[n].value),e[n].selected!==l&&(e[n].selected=l),l&&r&&(e[n].defaultSelected=!0)}else{for(n=""+Z(n),t=null,l=0;l<e.length;l++){if(e[l].value===n){e[l].selected=!0,r&&(e[l].defaultSelected=!0);return}t!==null||e[l].disabled||(t=e[l])}t!==null&&(t.selected=!0)}}function cn(e,t){if(t.dangerouslySetInnerHTML!=null)throw Error(v(91));return N({},t,{value:void 0,defaultValue:void 0,children:""+e._wrapperState.initialValue})}function $t(e,t){var n=t.value;if(n==null){if(n=t.children,t=t.defaultValue,n!=null){if(t!=null)throw Error(v(92));if(pt(n)){if(1<n.length)throw Error(v(93));n=n[0]}t=n}t==null&&(t=""),n=t}e._wrapperState={initialValue:Z(n)}}function Ar(e,t){var n=Z(t.value),r=Z(t.defaultValue);n!=null&&(n=""+n,n!==e.value&&(e.value=n),t.defaultValue==null&&e.defaultValue!==n&&(e.defaultValue=n)),r!=null&&(e.defaultValue=""+r)}function Zn(e){var t=e.textContent;t===e._wrapperState.initialValue&&t!==""&&t!==null&&(e.value=t)}function a(e){switch(e){case"svg":return"http://www.w3.org/2000/svg";case"math":return"http://www.w3.org/1998/Math/MathML";default:return"http://www.w3.org/1999/xhtml"}}function g(e,t){return e==null||e==="http://www.w3.org/1999/xhtml"?a(t):e==="http://www.w3.org/2000/svg"&&t==="foreignObject"?"http://www.w3.org/1999/xhtml":e}var V,ke=(function(e){return typeof MSApp<"u"&&MSApp.execUnsafeLocalFunction?function(t,n,r,l){MSApp.execUnsafeLocalFunction(function(){return e(t,n,r,l)})}:e})(function(e,t){if(e.namespaceURI!=="http://www.w3.org/2000/svg"||"innerHTML"in e)e.innerHTML=t;else{for(V=V||document.createElement("div"),V.innerHTML="<svg>"+t.valueOf().toString()+"</svg>",t=V.firstChild;e.firstChild;)e.removeChild(e.firstChild);for(;t.firstChild;)e.appendChild(t.firstChild)}});function mt(e,t){if(t){var n=e.firstChild;if(n&&n===e.lastChild&&n.nodeType===3){n.nodeValue=t;return}}e.textContent=t}var Rt={animationIterationCount:!0,aspectRatio:!0,borderImageOutset:!0,borderImageSlice:!0,borderImageWidth:!0,boxFlex:!0,boxFlexGroup:!0,boxOrdinalGroup:!0,columnCount:!0,columns:!0,flex:!0,flexGrow:!0,flexPositive:!0,flexShrink:!0,flexNegative:!0,flexOrder:!0,gridArea:!0,gridRow:!0,gridRowEnd:!0,gridRowSpan:!0,gridRowStart:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnSpan:!0,gridColumnStart:!0,fontWeight:!0,lineClamp:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,tabSize:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeDasharray:!0,strokeDashoffset:!0,strokeMiterlimit:!0,strokeOpacity:!0,strokeWidth:!0},Vr=["Webkit","ms","Moz","O"];Object.keys(Rt).forEach(function(e){Vr.forEach(function(t){t=t+e.charAt(0).toUpperCase()+e.substring(1),Rt[t]=Rt[e]})});function qn(e,t,n){return t==null||typeof t=="boolean"||t===""?"":n||typeof t!="number"||t===0||Rt.hasOwnProperty(e)&&Rt[e]?(""+t).trim():t+"px"}function Br(e,t){e=e.style;for(var n in t)if(t.hasOwnProperty(n)){var r=n.indexOf("--")===0,l=qn(n,t[n],r);n==="float"&&(n="cssFloat"),r?e.setProperty(n,l):e[n]=l}}var Zl=N({menuitem:!0},{area:!0,base:!0,br:!0,col:!0,embed:!0,hr:!0,img:!0,input:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0});function Ht(e,t){if(t){if(Zl[e]&&(t.children!=null||t.dangerouslySetInnerHTML!=null))throw Error(v(137,e));if(t.dangerouslySetInnerHTML!=null){if(t.children!=null)throw Error(v(60));if(typeof t.dangerouslySetInnerHTML!="object"||!("__html"in t.dangerouslySetInnerHTML))throw Error(v(61))}if(t.style!=null&&typeof t.style!="object")throw Error(v(62))}}function _n(e,t){if(e.indexOf("-")===-1)return typeof t.is=="string";switch(e){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var ql=null;function bl(e){return e=e.target||e.srcElement||window,e.correspondingUseElement&&(e=e.correspondingUseElement),e.nodeType===3?e.parentNode:e}var ei=null,jn=null,Pn=null;function Qu(e){if(e=kr(e)){if(typeof ei!="function")throw Error(v(280));var t=e.stateNode;t&&(t=cl(t),ei(e.stateNode,e.type,t))}}function Ku(e){jn?Pn?Pn.push(e):Pn=[e]:jn=e}function Yu(){if(jn){var e=jn,t=Pn;if(Pn=jn=null,Qu(e),t)for(e=0;e<t.length;e++)Qu(t[e])}}function Xu(e,t){return e(t)}function Gu(){}var ti=!1;function Ju(e,t,n){if(ti)return e(t,n);ti=!0;try{return Xu(e,t,n)}finally{ti=!1,(jn!==null||Pn!==null)&&(Gu(),Yu())}}function bn(e,t){var n=e.stateNode;if(n===null)return null;var r=cl(n);if(r===null)return null;n=r[t];e:switch(t){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(r=!r.disabled)||(e=e.type,r=!(e==="button"||e==="input"||e==="select"||e==="textarea")),e=!r;break e;default:e=!1}if(e)return null;if(n&&typeof n!="function")throw Error(v(231,t,typeof n));return n}var ni=!1;if(se)try{var er={};Object.defineProperty(er,"passive",{get:function(){ni=!0}}),window.addEventListener("test",er,er),window.removeEventListener("test",er,er)}catch{ni=!1}function Ua(e,t,n,r,l,i,u,o,s){var h=Array.prototype.slice.call(arguments,3);try{t.apply(n,h)}catch(k){this.onError(k)}}var tr=!1,$r=null,Hr=!1,ri=null,Aa={onError:function(e){tr=!0,$r=e}};function Va(e,t,n,r,l,i,u,o,s){tr=!1,$r=null,Ua.apply(Aa,arguments)}function Ba(e,t,n,r,l,i,u,o,s){if(Va.apply(this,arguments),tr){if(tr){var h=$r;tr=!1,$r=null}else throw Error(v(198));Hr||(Hr=!0,ri=h)}}function fn(e){var t=e,n=e;if(e.alternate)for(;t.return;)t=t.return;else{e=t;do t=e,(t.flags&4098)!==0&&(n=t.return),e=t.return;while(e)}return t.tag===3?n:null}function Zu(e){if(e.tag===13){var t=e.memoizedState;if(t===null&&(e=e.alternate,e!==null&&(t=e.memoizedState)),t!==null)return t.dehydrated}return null}function qu(e){if(fn(e)!==e)throw Error(v(188))}function $a(e){var t=e.alternate;if(!t){if(t=fn(e),t===null)throw Error(v(188));return t!==e?null:e}for(var n=e,r=t;;){var l=n.return;if(l===null)break;var i=l.alternate;if(i===null){if(r=l.return,r!==null){n=r;continue}break}if(l.child===i.child){for(i=l.child;i;){if(i===n)return qu(l),e;if(i===r)return qu(l),t;i=i.sibling}throw Error(v(188))}if(n.return!==r.return)n=l,r=i;else{for(var u=!1,o=l.child;o;){if(o===n){u=!0,n=l,r=i;break}if(o===r){u=!0,r=l,n=i;break}o=o.sibling}if(!u){for(o=i.child;o;){if(o===n){u=!0,n=i,r=l;break}if(o===r){u=!0,r=i,n=l;break}o=o.sibling}if(!u)throw Error(v(189))}}if(n.alternate!==r)throw Error(v(190))}if(n.tag!==3)throw Error(v(188));return n.stateNode.current===n?e:t}function bu(e){return e=$a(e),e!==null?eo(e):null}function eo(e){if(e.tag===5||e.tag===6)return e;for(e=e.child;e!==null;){var t=eo(e);if(t!==null)return t;e=e.sibling}return null}var to=B.unstable_scheduleCallback,no=B.unstable_cancelCallback,Ha=B.unstable_shouldYield,Wa=B.unstable_requestPaint,Se=B.unstable_now,Qa=B.unstable_getCurrentPriorityLevel,li=B.unstable_ImmediatePriority,ro=B.unstable_UserBlockingPriority,Wr=B.unstable_NormalPriority,Ka=B.unstable_LowPriority,lo=B.unstable_IdlePriority,Qr=null,_t=null;function Ya(e){if(_t&&typeof _t.onCommitFiberRoot=="function")try{_t.onCommitFiberRoot(Qr,e,void 0,(e.current.flags&128)===128)}catch{}}var ht=Math.clz32?Math.clz32:Ja,Xa=Math.log,Ga=Math.LN2;function Ja(e){return e>>>=0,e===0?32:31-(Xa(e)/Ga|0)|0}var Kr=64,Yr=4194304;function nr(e){switch(e&-e){case 1:return 1;case 2:return 2;case 4:return 4;case 8:return 8;case 16:return 16;case 32:return 32;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return e&4194240;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return e&130023424;case 134217728:return 134217728;case 268435456:return 268435456;case 536870912:return 536870912;case 1073741824:return 1073741824;default:return e}}function Xr(e,t){var n=e.pendingLanes;if(n===0)return 0;var r=0,l=e.suspendedLanes,i=e.pingedLanes,u=n&268435455;if(u!==0){var o=u&~l;o!==0?r=nr(o):(i&=u,i!==0&&(r=nr(i)))}else u=n&~l,u!==0?r=nr(u):i!==0&&(r=nr(i));if(r===0)return 0;if(t!==0&&t!==r&&(t&l)===0&&(l=r&-r,i=t&-t,l>=i||l===16&&(i&4194240)!==0))return t;if((r&4)!==0&&(r|=n&16),t=e.entangledLanes,t!==0)for(e=e.entanglements,t&=r;0<t;)n=31-ht(t),l=1<<n,r|=e[n],t&=~l;return r}function Za(e,t){switch(e){case 1:case 2:case 4:return t+250;case 8:case 16:case 32:case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return t+5e3;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return-1;case 134217728:case 268435456:case 536870912:case 1073741824:return-1;default:return-1}}function qa(e,t){for(var n=e.suspendedLanes,r=e.pingedLanes,l=e.expirationTimes,i=e.pendingLanes;0<i;){var u=31-ht(i),o=1<<u,s=l[u];s===-1?((o&n)===0||(o&r)!==0)&&(l[u]=Za(o,t)):s<=t&&(e.expiredLanes|=o),i&=~o}}function ii(e){return e=e.pendingLanes&-1073741825,e!==0?e:e&1073741824?1073741824:0}function io(){var e=Kr;return Kr<<=1,(Kr&4194240)===0&&(Kr=64),e}function ui(e){for(var t=[],n=0;31>n;n++)t.push(e);return t}function rr(e,t,n){e.pendingLanes|=t,t!==536870912&&(e.suspendedLanes=0,e.pingedLanes=0),e=e.eventTimes,t=31-ht(t),e[t]=n}function ba(e,t){var n=e.pendingLanes&~t;e.pendingLanes=t,e.suspendedLanes=0,e.pingedLanes=0,e.expiredLanes&=t,e.mutableReadLanes&=t,e.entangledLanes&=t,t=e.entanglements;var r=e.eventTimes;for(e=e.expirationTimes;0<n;){var l=31-ht(n),i=1<<l;t[l]=0,r[l]=-1,e[l]=-1,n&=~i}}function oi(e,t){var n=e.entangledLanes|=t;for(e=e.entanglements;n;){var r=31-ht(n),l=1<<r;l&t|e[r]&t&&(e[r]|=t),n&=~l}}var le=0;function uo(e){return e&=-e,1<e?4<e?(e&268435455)!==0?16:536870912:4:1}var oo,si,so,ao,co,ai=!1,Gr=[],Wt=null,Qt=null,Kt=null,lr=new Map,ir=new Map,Yt=[],ec="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset submit".split(" ");function fo(e,t){switch(e){case"focusin":case"focusout":Wt=null;break;case"dragenter":case"dragleave":Qt=null;break;case"mouseover":case"mouseout":Kt=null;break;case"pointerover":case"pointerout":lr.delete(t.pointerId);break;case"gotpointercapture":case"lostpointercapture":ir.delete(t.pointerId)}}function ur(e,t,n,r,l,i){return e===null||e.nativeEvent!==i?(e={blockedOn:t,domEventName:n,eventSystemFlags:r,nativeEvent:i,targetContainers:[l]},t!==null&&(t=kr(t),t!==null&&si(t)),e):(e.eventSystemFlags|=r,t=e.targetContainers,l!==null&&t.indexOf(l)===-1&&t.push(l),e)}function tc(e,t,n,r,l){switch(t){case"focusin":return Wt=ur(Wt,e,t,n,r,l),!0;case"dragenter":return Qt=ur(Qt,e,t,n,r,l),!0;case"mouseover":return Kt=ur(Kt,e,t,n,r,l),!0;case"pointerover":var i=l.pointerId;return lr.set(i,ur(lr.get(i)||null,e,t,n,r,l)),!0;case"gotpointercapture":return i=l.pointerId,ir.set(i,ur(ir.get(i)||null,e,t,n,r,l)),!0}return!1}function po(e){var t=dn(e.target);if(t!==null){var n=fn(t);if(n!==null){if(t=n.tag,t===13){if(t=Zu(n),t!==null){e.blockedOn=t,co(e.priority,function(){so(n)});return}}else if(t===3&&n.stateNode.current.memoizedState.isDehydrated){e.blockedOn=n.tag===3?n.stateNode.containerInfo:null;return}}}e.blockedOn=null}function Jr(e){if(e.blockedOn!==null)return!1;for(var t=e.targetContainers;0<t.length;){var n=fi(e.domEventName,e.eventSystemFlags,t[0],e.nativeEvent);if(n===null){n=e.nativeEvent;var r=new n.constructor(n.type,n);ql=r,n.target.dispatchEvent(r),ql=null}else return t=kr(n),t!==null&&si(t),e.blockedOn=n,!1;t.shift()}return!0}function mo(e,t,n){Jr(e)&&n.delete(t)}function nc(){ai=!1,Wt!==null&&Jr(Wt)&&(Wt=null),Qt!==null&&Jr(Qt)&&(Qt=null),Kt!==null&&Jr(Kt)&&(Kt=null),lr.forEach(mo),ir.forEach(mo)}function or(e,t){e.blockedOn===t&&(e.blockedOn=null,ai||(ai=!0,B.unstable_scheduleCallback(B.unstable_NormalPriority,nc)))}function sr(e){function t(l){return or(l,e)}if(0<Gr.length){or(Gr[0],e);for(var n=1;n<Gr.length;n++){var r=Gr[n];r.blockedOn===e&&(r.blockedOn=null)}}for(Wt!==null&&or(Wt,e),Qt!==null&&or(Qt,e),Kt!==null&&or(Kt,e),lr.forEach(t),ir.forEach(t),n=0;n<Yt.length;n++)r=Yt[n],r.blockedOn===e&&(r.blockedOn=null);for(;0<Yt.length&&(n=Yt[0],n.blockedOn===null);)po(n),n.blockedOn===null&&Yt.shift()}var zn=ee.ReactCurrentBatchConfig,Zr=!0;function rc(e,t,n,r){var l=le,i=zn.transition;zn.transition=null;try{le=1,ci(e,t,n,r)}finally{le=l,zn.transition=i}}function lc(e,t,n,r){var l=le,i=zn.transition;zn.transition=null;try{le=4,ci(e,t,n,r)}finally{le=l,zn.transition=i}}function ci(e,t,n,r){if(Zr){var l=fi(e,t,n,r);if(l===null)Pi(e,t,r,qr,n),fo(e,r);else if(tc(l,e,t,n,r))r.stopPropagation();else if(fo(e,r),t&4&&-1<ec.indexOf(e)){for(;l!==null;){var i=kr(l);if(i!==null&&oo(i),i=fi(e,t,n,r),i===null&&Pi(e,t,r,qr,n),i===l)break;l=i}l!==null&&r.stopPropagation()}else Pi(e,t,r,null,n)}}var qr=null;function fi(e,t,n,r){if(qr=null,e=bl(r),e=dn(e),e!==null)if(t=fn(e),t===null)e=null;else if(n=t.tag,n===13){if(e=Zu(t),e!==null)return e;e=null}else if(n===3){if(t.stateNode.current.memoizedState.isDehydrated)return t.tag===3?t.stateNode.containerInfo:null;e=null}else t!==e&&(e=null);return qr=e,null}function ho(e){switch(e){case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 1;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"toggle":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 4;case"message":switch(Qa()){case li:return 1;case ro:return 4;case Wr:case Ka:return 16;case lo:return 536870912;default:return 16}default:return 16}}var Xt=null,di=null,br=null;function vo(){if(br)return br;var e,t=di,n=t.length,r,l="value"in Xt?Xt.value:Xt.textContent,i=l.length;for(e=0;e<n&&t[e]===l[e];e++);var u=n-e;for(r=1;r<=u&&t[n-r]===l[i-r];r++);return br=l.slice(e,1<r?1-r:void 0)}function el(e){var t=e.keyCode;return"charCode"in e?(e=e.charCode,e===0&&t===13&&(e=13)):e=t,e===10&&(e=13),32<=e||e===13?e:0}function tl(){return!0}function yo(){return!1}function tt(e){function t(n,r,l,i,u){this._reactName=n,this._targetInst=l,this.type=r,this.nativeEvent=i,this.target=u,this.currentTarget=null;for(var o in e)e.hasOwnProperty(o)&&(n=e[o],this[o]=n?n(i):i[o]);return this.isDefaultPrevented=(i.defaultPrevented!=null?i.defaultPrevented:i.returnValue===!1)?tl:yo,this.isPropagationStopped=yo,this}return N(t.prototype,{preventDefault:function(){this.defaultPrevented=!0;var n=this.nativeEvent;n&&(n.preventDefault?n.preventDefault():typeof n.returnValue!="unknown"&&(n.returnValue=!1),this.isDefaultPrevented=tl)},stopPropagation:function(){var n=this.nativeEvent;n&&(n.stopPropagation?n.stopPropagation():typeof n.cancelBubble!="unknown"&&(n.cancelBubble=!0),this.isPropagationStopped=tl)},persist:function(){},isPersistent:tl}),t}var Ln={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(e){return e.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},pi=tt(Ln),ar=N({},Ln,{view:0,detail:0}),ic=tt(ar),mi,hi,cr,nl=N({},ar,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:yi,button:0,buttons:0,relatedTarget:function(e){return e.relatedTarget===void 0?e.fromElement===e.srcElement?e.toElement:e.fromElement:e.relatedTarget},movementX:function(e){return"movementX"in e?e.movementX:(e!==cr&&(cr&&e.type==="mousemove"?(mi=e.screenX-cr.screenX,hi=e.screenY-cr.screenY):hi=mi=0,cr=e),mi)},movementY:function(e){return"movementY"in e?e.movementY:hi}}),go=tt(nl),uc=N({},nl,{dataTransfer:0}),oc=tt(uc),sc=N({},ar,{relatedTarget:0}),vi=tt(sc),ac=N({},Ln,{animationName:0,elapsedTime:0,pseudoElement:0}),cc=tt(ac),fc=N({},Ln,{clipboardData:function(e){return"clipboardData"in e?e.clipboardData:window.clipboardData}}),dc=tt(fc),pc=N({},Ln,{data:0}),wo=tt(pc),mc={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},hc={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},vc={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function yc(e){var t=this.nativeEvent;return t.getModifierState?t.getModifierState(e):(e=vc[e])?!!t[e]:!1}function yi(){return yc}var gc=N({},ar,{key:function(e){if(e.key){var t=mc[e.key]||e.key;if(t!=="Unidentified")return t}return e.type==="keypress"?(e=el(e),e===13?"Enter":String.fromCharCode(e)):e.type==="keydown"||e.type==="keyup"?hc[e.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:yi,charCode:function(e){return e.type==="keypress"?el(e):0},keyCode:function(e){return e.type==="keydown"||e.type==="keyup"?e.keyCode:0},which:function(e){return e.type==="keypress"?el(e):e.type==="keydown"||e.type==="keyup"?e.keyCode:0}}),wc=tt(gc),kc=N({},nl,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),ko=tt(kc),Sc=N({},ar,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:yi}),xc=tt(Sc),Cc=N({},Ln,{propertyName:0,elapsedTime:0,pseudoElement:0}),Ec=tt(Cc),Nc=N({},nl,{deltaX:function(e){return"deltaX"in e?e.deltaX:"wheelDeltaX"in e?-e.wheelDeltaX:0},deltaY:function(e){return"deltaY"in e?e.deltaY:"wheelDeltaY"in e?-e.wheelDeltaY:"wheelDelta"in e?-e.wheelDelta:0},deltaZ:0,deltaMode:0}),_c=tt(Nc),jc=[9,13,27,32],gi=se&&"CompositionEvent"in window,fr=null;se&&"documentMode"in document&&(fr=document.documentMode);var Pc=se&&"TextEvent"in window&&!fr,So=se&&(!gi||fr&&8<fr&&11>=fr),xo=" ",Co=!1;function Eo(e,t){switch(e){case"keyup":return jc.indexOf(t.keyCode)!==-1;case"keydown":return t.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function No(e){return e=e.detail,typeof e=="object"&&"data"in e?e.data:null}var Tn=!1;function zc(e,t){switch(e){case"compositionend":return No(t);case"keypress":return t.which!==32?null:(Co=!0,xo);case"textInput":return e=t.data,e===xo&&Co?null:e;default:return null}}function Lc(e,t){if(Tn)return e==="compositionend"||!gi&&Eo(e,t)?(e=vo(),br=di=Xt=null,Tn=!1,e):null;switch(e){case"paste":return null;case"keypress":if(!(t.ctrlKey||t.altKey||t.metaKey)||t.ctrlKey&&t.altKey){if(t.char&&1<t.char.length)return t.char;if(t.which)return String.fromCharCode(t.which)}return null;case"compositionend":return So&&t.locale!=="ko"?null:t.data;default:return null}}var Tc={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function _o(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t==="input"?!!Tc[e.type]:t==="textarea"}function jo(e,t,n,r){Ku(r),t=ol(t,"onChange"),0<t.length&&(n=new pi("onChange","change",null,n,r),e.push({event:n,listeners:t}))}var dr=null,pr=null;function Rc(e){Qo(e,0)}function rl(e){var t=In(e);if(ye(t))return e}function Mc(e,t){if(e==="change")return t}var Po=!1;if(se){var wi;if(se){var ki="oninput"in document;if(!ki){var zo=document.createElement("div");zo.setAttribute("oninput","return;"),ki=typeof zo.oninput=="function"}wi=ki}else wi=!1;Po=wi&&(!document.documentMode||9<document.documentMode)}function Lo(){dr&&(dr.detachEvent("onpropertychange",To),pr=dr=null)}function To(e){if(e.propertyName==="value"&&rl(pr)){var t=[];jo(t,pr,e,bl(e)),Ju(Rc,t)}}function Oc(e,t,n){e==="focusin"?(Lo(),dr=t,pr=n,dr.attachEvent("onpropertychange",To)):e==="focusout"&&Lo()}function Fc(e){if(e==="selectionchange"||e==="keyup"||e==="keydown")return rl(pr)}function Ic(e,t){if(e==="click")return rl(t)}function Dc(e,t){if(e==="input"||e==="change")return rl(t)}function Uc(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var vt=typeof Object.is=="function"?Object.is:Uc;function mr(e,t){if(vt(e,t))return!0;if(typeof e!="object"||e===null||typeof t!="object"||t===null)return!1;var n=Object.keys(e),r=Object.keys(t);if(n.length!==r.length)return!1;for(r=0;r<n.length;r++){var l=n[r];if(!F.call(t,l)||!vt(e[l],t[l]))return!1}return!0}function Ro(e){for(;e&&e.firstChild;)e=e.firstChild;return e}function Mo(e,t){var n=Ro(e);e=0;for(var r;n;){if(n.nodeType===3){if(r=e+n.textContent.length,e<=t&&r>=t)return{node:n,offset:t-e};e=r}e:{for(;n;){if(n.nextSibling){n=n.nextSibling;break e}n=n.parentNode}n=void 0}n=Ro(n)}}function Oo(e,t){return e&&t?e===t?!0:e&&e.nodeType===3?!1:t&&t.nodeType===3?Oo(e,t.parentNode):"contains"in e?e.contains(t):e.compareDocumentPosition?!!(e.compareDocumentPosition(t)&16):!1:!1}function Fo(){for(var e=window,t=Ct();t instanceof e.HTMLIFrameElement;){try{var n=typeof t.contentWindow.location.href=="string"}catch{n=!1}if(n)e=t.contentWindow;else break;t=Ct(e.document)}return t}function Si(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t&&(t==="input"&&(e.type==="text"||e.type==="search"||e.type==="tel"||e.type==="url"||e.type==="password")||t==="textarea"||e.contentEditable==="true")}function Ac(e){var t=Fo(),n=e.focusedElem,r=e.selectionRange;if(t!==n&&n&&n.ownerDocument&&Oo(n.ownerDocument.documentElement,n)){if(r!==null&&Si(n)){if(t=r.start,e=r.end,e===void 0&&(e=t),"selectionStart"in n)n.selectionStart=t,n.selectionEnd=Math.min(e,n.value.length);else if(e=(t=n.ownerDocument||document)&&t.defaultView||window,e.getSelection){e=e.getSelection();var l=n.textContent.length,i=Math.min(r.start,l);r=r.end===void 0?i:Math.min(r.end,l),!e.extend&&i>r&&(l=r,r=i,i=l),l=Mo(n,i);var u=Mo(n,r);l&&u&&(e.rangeCount!==1||e.anchorNode!==l.node||e.anchorOffset!==l.offset||e.focusNode!==u.node||e.focusOffset!==u.offset)&&(t=t.createRange(),t.setStart(l.node,l.offset),e.removeAllRanges(),i>r?(e.addRange(t),e.extend(u.node,u.offset)):(t.setEnd(u.node,u.offset),e.addRange(t)))}}for(t=[],e=n;e=e.parentNode;)e.nodeType===1&&t.push({element:e,left:e.scrollLeft,top:e.scrollTop});for(typeof n.focus=="function"&&n.focus(),n=0;n<t.length;n++)e=t[n],e.element.scrollLeft=e.left,e.element.scrollTop=e.top}}var Vc=se&&"documentMode"in document&&11>=document.documentMode,Rn=null,xi=null,hr=null,Ci=!1;function Io(e,t,n){var r=n.window===n?n.document:n.nodeType===9?n:n.ownerDocument;Ci||Rn==null||Rn!==Ct(r)||(r=Rn,"selectionStart"in r&&Si(r)?r={start:r.selectionStart,end:r.selectionEnd}:(r=(r.ownerDocument&&r.ownerDocument.defaultView||window).getSelection(),r={anchorNode:r.anchorNode,anchorOffset:r.anchorOffset,focusNode:r.focusNode,focusOffset:r.focusOffset}),hr&&mr(hr,r)||(hr=r,r=ol(xi,"onSelect"),0<r.length&&(t=new pi("onSelect","select",null,t,n),e.push({event:t,listeners:r}),t.target=Rn)))}function ll(e,t){var n={};return n[e.toLowerCase()]=t.toLowerCase(),n["Webkit"+e]="webkit"+t,n["Moz"+e]="moz"+t,n}var Mn={animationend:ll("Animation","AnimationEnd"),animationiteration:ll("Animation","AnimationIteration"),animationstart:ll("Animation","AnimationStart"),transitionend:ll("Transition","TransitionEnd")},Ei={},Do={};se&&(Do=document.createElement("div").style,"AnimationEvent"in window||(delete Mn.animationend.animation,delete Mn.animationiteration.animation,delete Mn.animationstart.animation),"TransitionEvent"in window||delete Mn.transitionend.transition);function il(e){if(Ei[e])return Ei[e];if(!Mn[e])return e;var t=Mn[e],n;for(n in t)if(t.hasOwnProperty(n)&&n in Do)return Ei[e]=t[n];return e}var Uo=il("animationend"),Ao=il("animationiteration"),Vo=il("animationstart"),Bo=il("transitionend"),$o=new Map,Ho="abort auxClick cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");function Gt(e,t){$o.set(e,t),G(t,[e])}for(var Ni=0;Ni<Ho.length;Ni++){var _i=Ho[Ni],Bc=_i.toLowerCase(),$c=_i[0].toUpperCase()+_i.slice(1);Gt(Bc,"on"+$c)}Gt(Uo,"onAnimationEnd"),Gt(Ao,"onAnimationIteration"),Gt(Vo,"onAnimationStart"),Gt("dblclick","onDoubleClick"),Gt("focusin","onFocus"),Gt("focusout","onBlur"),Gt(Bo,"onTransitionEnd"),ce("onMouseEnter",["mouseout","mouseover"]),ce("onMouseLeave",["mouseout","mouseover"]),ce("onPointerEnter",["pointerout","pointerover"]),ce("onPointerLeave",["pointerout","pointerover"]),G("onChange","change click focusin focusout input keydown keyup selectionchange".split(" ")),G("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" ")),G("onBeforeInput",["compositionend","keypress","textInput","paste"]),G("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" ")),G("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" ")),G("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var vr="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),Hc=new Set("cancel close invalid load scroll toggle".split(" ").concat(vr));function Wo(e,t,n){var r=e.type||"unknown-event";e.currentTarget=n,Ba(r,t,void 0,e),e.currentTarget=null}function Qo(e,t){t=(t&4)!==0;for(var n=0;n<e.length;n++){var r=e[n],l=r.event;r=r.listeners;e:{var i=void 0;if(t)for(var u=r.length-1;0<=u;u--){var o=r[u],s=o.instance,h=o.currentTarget;if(o=o.listener,s!==i&&l.isPropagationStopped())break e;Wo(l,o,h),i=s}else for(u=0;u<r.length;u++){if(o=r[u],s=o.instance,h=o.currentTarget,o=o.listener,s!==i&&l.isPropagationStopped())break e;Wo(l,o,h),i=s}}}if(Hr)throw e=ri,Hr=!1,ri=null,e}function fe(e,t){var n=t[Oi];n===void 0&&(n=t[Oi]=new Set);var r=e+"__bubble";n.has(r)||(Ko(t,e,2,!1),n.add(r))}function ji(e,t,n){var r=0;t&&(r|=4),Ko(n,e,r,t)}var ul="_reactListening"+Math.random().toString(36).slice(2);function yr(e){if(!e[ul]){e[ul]=!0,Ce.forEach(function(n){n!=="selectionchange"&&(Hc.has(n)||ji(n,!1,e),ji(n,!0,e))});var t=e.nodeType===9?e:e.ownerDocument;t===null||t[ul]||(t[ul]=!0,ji("selectionchange",!1,t))}}function Ko(e,t,n,r){switch(ho(t)){case 1:var l=rc;break;case 4:l=lc;break;default:l=ci}n=l.bind(null,t,n,e),l=void 0,!ni||t!=="touchstart"&&t!=="touchmove"&&t!=="wheel"||(l=!0),r?l!==void 0?e.addEventListener(t,n,{capture:!0,passive:l}):e.addEventListener(t,n,!0):l!==void 0?e.addEventListener(t,n,{passive:l}):e.addEventListener(t,n,!1)}function Pi(e,t,n,r,l){var i=r;if((t&1)===0&&(t&2)===0&&r!==null)e:for(;;){if(r===null)return;var u=r.tag;if(u===3||u===4){var o=r.stateNode.containerInfo;if(o===l||o.nodeType===8&&o.parentNode===l)break;if(u===4)for(u=r.return;u!==null;){var s=u.tag;if((s===3||s===4)&&(s=u.stateNode.containerInfo,s===l||s.nodeType===8&&s.parentNode===l))return;u=u.return}for(;o!==null;){if(u=dn(o),u===null)return;if(s=u.tag,s===5||s===6){r=i=u;continue e}o=o.parentNode}}r=r.return}Ju(function(){var h=i,k=bl(n),S=[];e:{var w=$o.get(e);if(w!==void 0){var E=pi,j=e;switch(e){case"keypress":if(el(n)===0)break e;case"keydown":case"keyup":E=wc;break;case"focusin":j="focus",E=vi;break;case"focusout":j="blur",E=vi;break;case"beforeblur":case"afterblur":E=vi;break;case"click":if(n.button===2)break e;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":E=go;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":E=oc;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":E=xc;break;case Uo:case Ao:case Vo:E=cc;break;case Bo:E=Ec;break;case"scroll":E=ic;break;case"wheel":E=_c;break;case"copy":case"cut":case"paste":E=dc;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":E=ko}var P=(t&4)!==0,xe=!P&&e==="scroll",p=P?w!==null?w+"Capture":null:w;P=[];for(var c=h,m;c!==null;){m=c;var x=m.stateNode;if(m.tag===5&&x!==null&&(m=x,p!==null&&(x=bn(c,p),x!=null&&P.push(gr(c,x,m)))),xe)break;c=c.return}0<P.length&&(w=new E(w,j,null,n,k),S.push({event:w,listeners:P}))}}if((t&7)===0){e:{if(w=e==="mouseover"||e==="pointerover",E=e==="mouseout"||e==="pointerout",w&&n!==ql&&(j=n.relatedTarget||n.fromElement)&&(dn(j)||j[Mt]))break e;if((E||w)&&(w=k.window===k?k:(w=k.ownerDocument)?w.defaultView||w.parentWindow:window,E?(j=n.relatedTarget||n.toElement,E=h,j=j?dn(j):null,j!==null&&(xe=fn(j),j!==xe||j.tag!==5&&j.tag!==6)&&(j=null)):(E=null,j=h),E!==j)){if(P=go,x="onMouseLeave",p="onMouseEnter",c="mouse",(e==="pointerout"||e==="pointerover")&&(P=ko,x="onPointerLeave",p="onPointerEnter",c="pointer"),xe=E==null?w:In(E),m=j==null?w:In(j),w=new P(x,c+"leave",E,n,k),w.target=xe,w.relatedTarget=m,x=null,dn(k)===h&&(P=new P(p,c+"enter",j,n,k),P.target=m,P.relatedTarget=xe,x=P),xe=x,E&&j)t:{for(P=E,p=j,c=0,m=P;m;m=On(m))c++;for(m=0,x=p;x;x=On(x))m++;for(;0<c-m;)P=On(P),c--;for(;0<m-c;)p=On(p),m--;for(;c--;){if(P===p||p!==null&&P===p.alternate)break t;P=On(P),p=On(p)}P=null}else P=null;E!==null&&Yo(S,w,E,P,!1),j!==null&&xe!==null&&Yo(S,xe,j,P,!0)}}e:{if(w=h?In(h):window,E=w.nodeName&&w.nodeName.toLowerCase(),E==="select"||E==="input"&&w.type==="file")var z=Mc;else if(_o(w))if(Po)z=Dc;else{z=Fc;var M=Oc}else(E=w.nodeName)&&E.toLowerCase()==="input"&&(w.type==="checkbox"||w.type==="radio")&&(z=Ic);if(z&&(z=z(e,h))){jo(S,z,n,k);break e}M&&M(e,w,h),e==="focusout"&&(M=w._wrapperState)&&M.controlled&&w.type==="number"&&Tt(w,"number",w.value)}switch(M=h?In(h):window,e){case"focusin":(_o(M)||M.contentEditable==="true")&&(Rn=M,xi=h,hr=null);break;case"focusout":hr=xi=Rn=null;break;case"mousedown":Ci=!0;break;case"contextmenu":case"mouseup":case"dragend":Ci=!1,Io(S,n,k);break;case"selectionchange":if(Vc)break;case"keydown":case"keyup":Io(S,n,k)}var O;if(gi)e:{switch(e){case"compositionstart":var D="onCompositionStart";break e;case"compositionend":D="onCompositionEnd";break e;case"compositionupdate":D="onCompositionUpdate";break e}D=void 0}else Tn?Eo(e,n)&&(D="onCompositionEnd"):e==="keydown"&&n.keyCode===229&&(D="onCompositionStart");D&&(So&&n.locale!=="ko"&&(Tn||D!=="onCompositionStart"?D==="onCompositionEnd"&&Tn&&(O=vo()):(Xt=k,di="value"in Xt?Xt.value:Xt.textContent,Tn=!0)),M=ol(h,D),0<M.length&&(D=new wo(D,e,null,n,k),S.push({event:D,listeners:M}),O?D.data=O:(O=No(n),O!==null&&(D.data=O)))),(O=Pc?zc(e,n):Lc(e,n))&&(h=ol(h,"onBeforeInput"),0<h.length&&(k=new wo("onBeforeInput","beforeinput",null,n,k),S.push({event:k,listeners:h}),k.data=O))}Qo(S,t)})}function gr(e,t,n){return{instance:e,listener:t,currentTarget:n}}function ol(e,t){for(var n=t+"Capture",r=[];e!==null;){var l=e,i=l.stateNode;l.tag===5&&i!==null&&(l=i,i=bn(e,n),i!=null&&r.unshift(gr(e,i,l)),i=bn(e,t),i!=null&&r.push(gr(e,i,l))),e=e.return}return r}function On(e){if(e===null)return null;do e=e.return;while(e&&e.tag!==5);return e||null}function Yo(e,t,n,r,l){for(var i=t._reactName,u=[];n!==null&&n!==r;){var o=n,s=o.alternate,h=o.stateNode;if(s!==null&&s===r)break;o.tag===5&&h!==null&&(o=h,l?(s=bn(n,i),s!=null&&u.unshift(gr(n,s,o))):l||(s=bn(n,i),s!=null&&u.push(gr(n,s,o)))),n=n.return}u.length!==0&&e.push({event:t,listeners:u})}var Wc=/\r\n?/g,Qc=/\u0000|\uFFFD/g;function Xo(e){return(typeof e=="string"?e:""+e).replace(Wc,`
-`).replace(Qc,"")}function sl(e,t,n){if(t=Xo(t),Xo(e)!==t&&n)throw Error(v(425))}function al(){}var zi=null,Li=null;function Ti(e,t){return e==="textarea"||e==="noscript"||typeof t.children=="string"||typeof t.children=="number"||typeof t.dangerouslySetInnerHTML=="object"&&t.dangerouslySetInnerHTML!==null&&t.dangerouslySetInnerHTML.__html!=null}var Ri=typeof setTimeout=="function"?setTimeout:void 0,Kc=typeof clearTimeout=="function"?clearTimeout:void 0,Go=typeof Promise=="function"?Promise:void 0,Yc=typeof queueMicrotask=="function"?queueMicrotask:typeof Go<"u"?function(e){return Go.resolve(null).then(e).catch(Xc)}:Ri;function Xc(e){setTimeout(function(){throw e})}function Mi(e,t){var n=t,r=0;do{var l=n.nextSibling;if(e.removeChild(n),l&&l.nodeType===8)if(n=l.data,n==="/$"){if(r===0){e.removeChild(l),sr(t);return}r--}else n!=="$"&&n!=="$?"&&n!=="$!"||r++;n=l}while(n);sr(t)}function Jt(e){for(;e!=null;e=e.nextSibling){var t=e.nodeType;if(t===1||t===3)break;if(t===8){if(t=e.data,t==="$"||t==="$!"||t==="$?")break;if(t==="/$")return null}}return e}function Jo(e){e=e.previousSibling;for(var t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="$"||n==="$!"||n==="$?"){if(t===0)return e;t--}else n==="/$"&&t++}e=e.previousSibling}return null}var Fn=Math.random().toString(36).slice(2),jt="__reactFiber$"+Fn,wr="__reactProps$"+Fn,Mt="__reactContainer$"+Fn,Oi="__reactEvents$"+Fn,Gc="__reactListeners$"+Fn,Jc="__reactHandles$"+Fn;function dn(e){var t=e[jt];if(t)return t;for(var n=e.parentNode;n;){if(t=n[Mt]||n[jt]){if(n=t.alternate,t.child!==null||n!==null&&n.child!==null)for(e=Jo(e);e!==null;){if(n=e[jt])return n;e=Jo(e)}return t}e=n,n=e.parentNode}return null}function kr(e){return e=e[jt]||e[Mt],!e||e.tag!==5&&e.tag!==6&&e.tag!==13&&e.tag!==3?null:e}function In(e){if(e.tag===5||e.tag===6)return e.stateNode;throw Error(v(33))}function cl(e){return e[wr]||null}var Fi=[],Dn=-1;function Zt(e){return{current:e}}function de(e){0>Dn||(e.current=Fi[Dn],Fi[Dn]=null,Dn--)}function ae(e,t){Dn++,Fi[Dn]=e.current,e.current=t}var qt={},Ae=Zt(qt),Xe=Zt(!1),pn=qt;function Un(e,t){var n=e.type.contextTypes;if(!n)return qt;var r=e.stateNode;if(r&&r.__reactInternalMemoizedUnmaskedChildContext===t)return r.__reactInternalMemoizedMaskedChildContext;var l={},i;for(i in n)l[i]=t[i];return r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=t,e.__reactInternalMemoizedMaskedChildContext=l),l}function Ge(e){return e=e.childContextTypes,e!=null}function fl(){de(Xe),de(Ae)}function Zo(e,t,n){if(Ae.current!==qt)throw Error(v(168));ae(Ae,t),ae(Xe,n)}function qo(e,t,n){var r=e.stateNode;if(t=t.childContextTypes,typeof r.getChildContext!="function")return n;r=r.getChildContext();for(var l in r)if(!(l in t))throw Error(v(108,re(e)||"Unknown",l));return N({},n,r)}function dl(e){return e=(e=e.stateNode)&&e.__reactInternalMemoizedMergedChildContext||qt,pn=Ae.current,ae(Ae,e),ae(Xe,Xe.current),!0}function bo(e,t,n){var r=e.stateNode;if(!r)throw Error(v(169));n?(e=qo(e,t,pn),r.__reactInternalMemoizedMergedChildContext=e,de(Xe),de(Ae),ae(Ae,e)):de(Xe),ae(Xe,n)}var Ot=null,pl=!1,Ii=!1;function es(e){Ot===null?Ot=[e]:Ot.push(e)}function Zc(e){pl=!0,es(e)}function bt(){if(!Ii&&Ot!==null){Ii=!0;var e=0,t=le;try{var n=Ot;for(le=1;e<n.length;e++){var r=n[e];do r=r(!0);while(r!==null)}Ot=null,pl=!1}catch(l){throw Ot!==null&&(Ot=Ot.slice(e+1)),to(li,bt),l}finally{le=t,Ii=!1}}return null}var An=[],Vn=0,ml=null,hl=0,ut=[],ot=0,mn=null,Ft=1,It="";function hn(e,t){An[Vn++]=hl,An[Vn++]=ml,ml=e,hl=t}function ts(e,t,n){ut[ot++]=Ft,ut[ot++]=It,ut[ot++]=mn,mn=e;var r=Ft;e=It;var l=32-ht(r)-1;r&=~(1<<l),n+=1;var i=32-ht(t)+l;if(30<i){var u=l-l%5;i=(r&(1<<u)-1).toString(32),r>>=u,l-=u,Ft=1<<32-ht(t)+l|n<<l|r,It=i+e}else Ft=1<<i|n<<l|r,It=e}function Di(e){e.return!==null&&(hn(e,1),ts(e,1,0))}function Ui(e){for(;e===ml;)ml=An[--Vn],An[Vn]=null,hl=An[--Vn],An[Vn]=null;for(;e===mn;)mn=ut[--ot],ut[ot]=null,It=ut[--ot],ut[ot]=null,Ft=ut[--ot],ut[ot]=null}var nt=null,rt=null,pe=!1,yt=null;function ns(e,t){var n=ft(5,null,null,0);n.elementType="DELETED",n.stateNode=t,n.return=e,t=e.deletions,t===null?(e.deletions=[n],e.flags|=16):t.push(n)}function rs(e,t){switch(e.tag){case 5:var n=e.type;return t=t.nodeType!==1||n.toLowerCase()!==t.nodeName.toLowerCase()?null:t,t!==null?(e.stateNode=t,nt=e,rt=Jt(t.firstChild),!0):!1;case 6:return t=e.pendingProps===""||t.nodeType!==3?null:t,t!==null?(e.stateNode=t,nt=e,rt=null,!0):!1;case 13:return t=t.nodeType!==8?null:t,t!==null?(n=mn!==null?{id:Ft,overflow:It}:null,e.memoizedState={dehydrated:t,treeContext:n,retryLane:1073741824},n=ft(18,null,null,0),n.stateNode=t,n.return=e,e.child=n,nt=e,rt=null,!0):!1;default:return!1}}function Ai(e){return(e.mode&1)!==0&&(e.flags&128)===0}function Vi(e){if(pe){var t=rt;if(t){var n=t;if(!rs(e,t)){if(Ai(e))throw Error(v(418));t=Jt(n.nextSibling);var r=nt;t&&rs(e,t)?ns(r,n):(e.flags=e.flags&-4097|2,pe=!1,nt=e)}}else{if(Ai(e))throw Error(v(418));e.flags=e.flags&-4097|2,pe=!1,nt=e}}}function ls(e){for(e=e.return;e!==null&&e.tag!==5&&e.tag!==3&&e.tag!==13;)e=e.return;nt=e}function vl(e){if(e!==nt)return!1;if(!pe)return ls(e),pe=!0,!1;var t;if((t=e.tag!==3)&&!(t=e.tag!==5)&&(t=e.type,t=t!=="head"&&t!=="body"&&!Ti(e.type,e.memoizedProps)),t&&(t=rt)){if(Ai(e))throw is(),Error(v(418));for(;t;)ns(e,t),t=Jt(t.nextSibling)}if(ls(e),e.tag===13){if(e=e.memoizedState,e=e!==null?e.dehydrated:null,!e)throw Error(v(317));e:{for(e=e.nextSibling,t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="/$"){if(t===0){rt=Jt(e.nextSibling);break e}t--}else n!=="$"&&n!=="$!"&&n!=="$?"||t++}e=e.nextSibling}rt=null}}else rt=nt?Jt(e.stateNode.nextSibling):null;return!0}function is(){for(var e=rt;e;)e=Jt(e.nextSibling)}function Bn(){rt=nt=null,pe=!1}function Bi(e){yt===null?yt=[e]:yt.push(e)}var qc=ee.ReactCurrentBatchConfig;function Sr(e,t,n){if(e=n.ref,e!==null&&typeof e!="function"&&typeof e!="object"){if(n._owner){if(n=n._owner,n){if(n.tag!==1)throw Error(v(309));var r=n.stateNode}if(!r)throw Error(v(147,e));var l=r,i=""+e;return t!==null&&t.ref!==null&&typeof t.ref=="function"&&t.ref._stringRef===i?t.ref:(t=function(u){var o=l.refs;u===null?delete o[i]:o[i]=u},t._stringRef=i,t)}if(typeof e!="string")throw Error(v(284));if(!n._owner)throw Error(v(290,e))}return e}function yl(e,t){throw e=Object.prototype.toString.call(t),Error(v(31,e==="[object Object]"?"object with keys {"+Object.keys(t).join(", ")+"}":e))}function us(e){var t=e._init;return t(e._payload)}function os(e){function t(p,c){if(e){var m=p.deletions;m===null?(p.deletions=[c],p.flags|=16):m.push(c)}}function n(p,c){if(!e)return null;for(;c!==null;)t(p,c),c=c.sibling;return null}function r(p,c){for(p=new Map;c!==null;)c.key!==null?p.set(c.key,c):p.set(c.index,c),c=c.sibling;return p}function l(p,c){return p=sn(p,c),p.index=0,p.sibling=null,p}function i(p,c,m){return p.index=m,e?(m=p.alternate,m!==null?(m=m.index,m<c?(p.flags|=2,c):m):(p.flags|=2,c)):(p.flags|=1048576,c)}function u(p){return e&&p.alternate===null&&(p.flags|=2),p}function o(p,c,m,x){return c===null||c.tag!==6?(c=Ru(m,p.mode,x),c.return=p,c):(c=l(c,m),c.return=p,c)}function s(p,c,m,x){var z=m.type;return z===Pe?k(p,c,m.props.children,x,m.key):c!==null&&(c.elementType===z||typeof z=="object"&&z!==null&&z.$$typeof===Ne&&us(z)===c.type)?(x=l(c,m.props),x.ref=Sr(p,c,m),x.return=p,x):(x=Bl(m.type,m.key,m.props,null,p.mode,x),x.ref=Sr(p,c,m),x.return=p,x)}function h(p,c,m,x){return c===null||c.tag!==4||c.stateNode.containerInfo!==m.containerInfo||c.stateNode.implementation!==m.implementation?(c=Mu(m,p.mode,x),c.return=p,c):(c=l(c,m.children||[]),c.return=p,c)}function k(p,c,m,x,z){return c===null||c.tag!==7?(c=Cn(m,p.mode,x,z),c.return=p,c):(c=l(c,m),c.return=p,c)}function S(p,c,m){if(typeof c=="string"&&c!==""||typeof c=="number")return c=Ru(""+c,p.mode,m),c.return=p,c;if(typeof c=="object"&&c!==null){switch(c.$$typeof){case et:return m=Bl(c.type,c.key,c.props,null,p.mode,m),m.ref=Sr(p,null,c),m.return=p,m;case T:return c=Mu(c,p.mode,m),c.return=p,c;case Ne:var x=c._init;return S(p,x(c._payload),m)}if(pt(c)||R(c))return c=Cn(c,p.mode,m,null),c.return=p,c;yl(p,c)}return null}function w(p,c,m,x){var z=c!==null?c.key:null;if(typeof m=="string"&&m!==""||typeof m=="number")return z!==null?null:o(p,c,""+m,x);if(typeof m=="object"&&m!==null){switch(m.$$typeof){case et:return m.key===z?s(p,c,m,x):null;case T:return m.key===z?h(p,c,m,x):null;case Ne:return z=m._init,w(p,c,z(m._payload),x)}if(pt(m)||R(m))return z!==null?null:k(p,c,m,x,null);yl(p,m)}return null}function E(p,c,m,x,z){if(typeof x=="string"&&x!==""||typeof x=="number")return p=p.get(m)||null,o(c,p,""+x,z);if(typeof x=="object"&&x!==null){switch(x.$$typeof){case et:return p=p.get(x.key===null?m:x.key)||null,s(c,p,x,z);case T:return p=p.get(x.key===null?m:x.key)||null,h(c,p,x,z);case Ne:var M=x._init;return E(p,c,m,M(x._payload),z)}if(pt(x)||R(x))return p=p.get(m)||null,k(c,p,x,z,null);yl(c,x)}return null}function j(p,c,m,x){for(var z=null,M=null,O=c,D=c=0,Te=null;O!==null&&D<m.length;D++){O.index>D?(Te=O,O=null):Te=O.sibling;var q=w(p,O,m[D],x);if(q===null){O===null&&(O=Te);break}e&&O&&q.alternate===null&&t(p,O),c=i(q,c,D),M===null?z=q:M.sibling=q,M=q,O=Te}if(D===m.length)return n(p,O),pe&&hn(p,D),z;if(O===null){for(;D<m.length;D++)O=S(p,m[D],x),O!==null&&(c=i(O,c,D),M===null?z=O:M.sibling=O,M=O);return pe&&hn(p,D),z}for(O=r(p,O);D<m.length;D++)Te=E(O,p,D,m[D],x),Te!==null&&(e&&Te.alternate!==null&&O.delete(Te.key===null?D:Te.key),c=i(Te,c,D),M===null?z=Te:M.sibling=Te,M=Te);return e&&O.forEach(function(an){return t(p,an)}),pe&&hn(p,D),z}function P(p,c,m,x){var z=R(m);if(typeof z!="function")throw Error(v(150));if(m=z.call(m),m==null)throw Error(v(151));for(var M=z=null,O=c,D=c=0,Te=null,q=m.next();O!==null&&!q.done;D++,q=m.next()){O.index>D?(Te=O,O=null):Te=O.sibling;var an=w(p,O,q.value,x);if(an===null){O===null&&(O=Te);break}e&&O&&an.alternate===null&&t(p,O),c=i(an,c,D),M===null?z=an:M.sibling=an,M=an,O=Te}if(q.done)return n(p,O),pe&&hn(p,D),z;if(O===null){for(;!q.done;D++,q=m.next())q=S(p,q.value,x),q!==null&&(c=i(q,c,D),M===null?z=q:M.sibling=q,M=q);return pe&&hn(p,D),z}for(O=r(p,O);!q.done;D++,q=m.next())q=E(O,p,D,q.value,x),q!==null&&(e&&q.alternate!==null&&O.delete(q.key===null?D:q.key),c=i(q,c,D),M===null?z=q:M.sibling=q,M=q);return e&&O.forEach(function(Rf){return t(p,Rf)}),pe&&hn(p,D),z}function xe(p,c,m,x){if(typeof m=="object"&&m!==null&&m.type===Pe&&m.key===null&&(m=m.props.children),typeof m=="object"&&m!==null){switch(m.$$typeof){case et:e:{for(var z=m.key,M=c;M!==null;){if(M.key===z){if(z=m.type,z===Pe){if(M.tag===7){n(p,M.sibling),c=l(M,m.props.children),c.return=p,p=c;break e}}else if(M.elementType===z||typeof z=="object"&&z!==null&&z.$$typeof===Ne&&us(z)===M.type){n(p,M.sibling),c=l(M,m.props),c.ref=Sr(p,M,m),c.return=p,p=c;break e}n(p,M);break}else t(p,M);M=M.sibling}m.type===Pe?(c=Cn(m.props.children,p.mode,x,m.key),c.return=p,p=c):(x=Bl(m.type,m.key,m.props,null,p.mode,x),x.ref=Sr(p,c,m),x.return=p,p=x)}return u(p);case T:e:{for(M=m.key;c!==null;){if(c.key===M)if(c.tag===4&&c.stateNode.containerInfo===m.containerInfo&&c.stateNode.implementation===m.implementation){n(p,c.sibling),c=l(c,m.children||[]),c.return=p,p=c;break e}else{n(p,c);break}else t(p,c);c=c.sibling}c=Mu(m,p.mode,x),c.return=p,p=c}return u(p);case Ne:return M=m._init,xe(p,c,M(m._payload),x)}if(pt(m))return j(p,c,m,x);if(R(m))return P(p,c,m,x);yl(p,m)}return typeof m=="string"&&m!==""||typeof m=="number"?(m=""+m,c!==null&&c.tag===6?(n(p,c.sibling),c=l(c,m),c.return=p,p=c):(n(p,c),c=Ru(m,p.mode,x),c.return=p,p=c),u(p)):n(p,c)}return xe}var $n=os(!0),ss=os(!1),gl=Zt(null),wl=null,Hn=null,$i=null;function Hi(){$i=Hn=wl=null}function Wi(e){var t=gl.current;de(gl),e._currentValue=t}function Qi(e,t,n){for(;e!==null;){var r=e.alternate;if((e.childLanes&t)!==t?(e.childLanes|=t,r!==null&&(r.childLanes|=t)):r!==null&&(r.childLanes&t)!==t&&(r.childLanes|=t),e===n)break;e=e.return}}function Wn(e,t){wl=e,$i=Hn=null,e=e.dependencies,e!==null&&e.firstContext!==null&&((e.lanes&t)!==0&&(Je=!0),e.firstContext=null)}function st(e){var t=e._currentValue;if($i!==e)if(e={context:e,memoizedValue:t,next:null},Hn===null){if(wl===null)throw Error(v(308));Hn=e,wl.dependencies={lanes:0,firstContext:e}}else Hn=Hn.next=e;return t}var vn=null;function Ki(e){vn===null?vn=[e]:vn.push(e)}function as(e,t,n,r){var l=t.interleaved;return l===null?(n.next=n,Ki(t)):(n.next=l.next,l.next=n),t.interleaved=n,Dt(e,r)}function Dt(e,t){e.lanes|=t;var n=e.alternate;for(n!==null&&(n.lanes|=t),n=e,e=e.return;e!==null;)e.childLanes|=t,n=e.alternate,n!==null&&(n.childLanes|=t),n=e,e=e.return;return n.tag===3?n.stateNode:null}var en=!1;function Yi(e){e.updateQueue={baseState:e.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,interleaved:null,lanes:0},effects:null}}function cs(e,t){e=e.updateQueue,t.updateQueue===e&&(t.updateQueue={baseState:e.baseState,firstBaseUpdate:e.firstBaseUpdate,lastBaseUpdate:e.lastBaseUpdate,shared:e.shared,effects:e.effects})}function Ut(e,t){return{eventTime:e,lane:t,tag:0,payload:null,callback:null,next:null}}function tn(e,t,n){var r=e.updateQueue;if(r===null)return null;if(r=r.shared,(J&2)!==0){var l=r.pending;return l===null?t.next=t:(t.next=l.next,l.next=t),r.pending=t,Dt(e,n)}return l=r.interleaved,l===null?(t.next=t,Ki(r)):(t.next=l.next,l.next=t),r.interleaved=t,Dt(e,n)}function kl(e,t,n){if(t=t.updateQueue,t!==null&&(t=t.shared,(n&4194240)!==0)){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,oi(e,n)}}function fs(e,t){var n=e.updateQueue,r=e.alternate;if(r!==null&&(r=r.updateQueue,n===r)){var l=null,i=null;if(n=n.firstBaseUpdate,n!==null){do{var u={eventTime:n.eventTime,lane:n.lane,tag:n.tag,payload:n.payload,callback:n.callback,next:null};i===null?l=i=u:i=i.next=u,n=n.next}while(n!==null);i===null?l=i=t:i=i.next=t}else l=i=t;n={baseState:r.baseState,firstBaseUpdate:l,lastBaseUpdate:i,shared:r.shared,effects:r.effects},e.updateQueue=n;return}e=n.lastBaseUpdate,e===null?n.firstBaseUpdate=t:e.next=t,n.lastBaseUpdate=t}function Sl(e,t,n,r){var l=e.updateQueue;en=!1;var i=l.firstBaseUpdate,u=l.lastBaseUpdate,o=l.shared.pending;if(o!==null){l.shared.pending=null;var s=o,h=s.next;s.next=null,u===null?i=h:u.next=h,u=s;var k=e.alternate;k!==null&&(k=k.updateQueue,o=k.lastBaseUpdate,o!==u&&(o===null?k.firstBaseUpdate=h:o.next=h,k.lastBaseUpdate=s))}if(i!==null){var S=l.baseState;u=0,k=h=s=null,o=i;do{var w=o.lane,E=o.eventTime;if((r&w)===w){k!==null&&(k=k.next={eventTime:E,lane:0,tag:o.tag,payload:o.payload,callback:o.callback,next:null});e:{var j=e,P=o;switch(w=t,E=n,P.tag){case 1:if(j=P.payload,typeof j=="function"){S=j.call(E,S,w);break e}S=j;break e;case 3:j.flags=j.flags&-65537|128;case 0:if(j=P.payload,w=typeof j=="function"?j.call(E,S,w):j,w==null)break e;S=N({},S,w);break e;case 2:en=!0}}o.callback!==null&&o.lane!==0&&(e.flags|=64,w=l.effects,w===null?l.effects=[o]:w.push(o))}else E={eventTime:E,lane:w,tag:o.tag,payload:o.payload,callback:o.callback,next:null},k===null?(h=k=E,s=S):k=k.next=E,u|=w;if(o=o.next,o===null){if(o=l.shared.pending,o===null)break;w=o,o=w.next,w.next=null,l.lastBaseUpdate=w,l.shared.pending=null}}while(!0);if(k===null&&(s=S),l.baseState=s,l.firstBaseUpdate=h,l.lastBaseUpdate=k,t=l.shared.interleaved,t!==null){l=t;do u|=l.lane,l=l.next;while(l!==t)}else i===null&&(l.shared.lanes=0);wn|=u,e.lanes=u,e.memoizedState=S}}function ds(e,t,n){if(e=t.effects,t.effects=null,e!==null)for(t=0;t<e.length;t++){var r=e[t],l=r.callback;if(l!==null){if(r.callback=null,r=n,typeof l!="function")throw Error(v(191,l));l.call(r)}}}var xr={},Pt=Zt(xr),Cr=Zt(xr),Er=Zt(xr);function yn(e){if(e===xr)throw Error(v(174));return e}function Xi(e,t){switch(ae(Er,t),ae(Cr,e),ae(Pt,xr),e=t.nodeType,e){case 9:case 11:t=(t=t.documentElement)?t.namespaceURI:g(null,"");break;default:e=e===8?t.parentNode:t,t=e.namespaceURI||null,e=e.tagName,t=g(t,e)}de(Pt),ae(Pt,t)}function Qn(){de(Pt),de(Cr),de(Er)}function ps(e){yn(Er.current);var t=yn(Pt.current),n=g(t,e.type);t!==n&&(ae(Cr,e),ae(Pt,n))}function Gi(e){Cr.current===e&&(de(Pt),de(Cr))}var he=Zt(0);function xl(e){for(var t=e;t!==null;){if(t.tag===13){var n=t.memoizedState;if(n!==null&&(n=n.dehydrated,n===null||n.data==="$?"||n.data==="$!"))return t}else if(t.tag===19&&t.memoizedProps.revealOrder!==void 0){if((t.flags&128)!==0)return t}else if(t.child!==null){t.child.return=t,t=t.child;continue}if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return null;t=t.return}t.sibling.return=t.return,t=t.sibling}return null}var Ji=[];function Zi(){for(var e=0;e<Ji.length;e++)Ji[e]._workInProgressVersionPrimary=null;Ji.length=0}var Cl=ee.ReactCurrentDispatcher,qi=ee.ReactCurrentBatchConfig,gn=0,ve=null,_e=null,ze=null,El=!1,Nr=!1,_r=0,bc=0;function Ve(){throw Error(v(321))}function bi(e,t){if(t===null)return!1;for(var n=0;n<t.length&&n<e.length;n++)if(!vt(e[n],t[n]))return!1;return!0}function eu(e,t,n,r,l,i){if(gn=i,ve=t,t.memoizedState=null,t.updateQueue=null,t.lanes=0,Cl.current=e===null||e.memoizedState===null?rf:lf,e=n(r,l),Nr){i=0;do{if(Nr=!1,_r=0,25<=i)throw Error(v(301));i+=1,ze=_e=null,t.updateQueue=null,Cl.current=uf,e=n(r,l)}while(Nr)}if(Cl.current=jl,t=_e!==null&&_e.next!==null,gn=0,ze=_e=ve=null,El=!1,t)throw Error(v(300));return e}function tu(){var e=_r!==0;return _r=0,e}function zt(){var e={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return ze===null?ve.memoizedState=ze=e:ze=ze.next=e,ze}function at(){if(_e===null){var e=ve.alternate;e=e!==null?e.memoizedState:null}else e=_e.next;var t=ze===null?ve.memoizedState:ze.next;if(t!==null)ze=t,_e=e;else{if(e===null)throw Error(v(310));_e=e,e={memoizedState:_e.memoizedState,baseState:_e.baseState,baseQueue:_e.baseQueue,queue:_e.queue,next:null},ze===null?ve.memoizedState=ze=e:ze=ze.next=e}return ze}function jr(e,t){return typeof t=="function"?t(e):t}function nu(e){var t=at(),n=t.queue;if(n===null)throw Error(v(311));n.lastRenderedReducer=e;var r=_e,l=r.baseQueue,i=n.pending;if(i!==null){if(l!==null){var u=l.next;l.next=i.next,i.next=u}r.baseQueue=l=i,n.pending=null}if(l!==null){i=l.next,r=r.baseState;var o=u=null,s=null,h=i;do{var k=h.lane;if((gn&k)===k)s!==null&&(s=s.next={lane:0,action:h.action,hasEagerState:h.hasEagerState,eagerState:h.eagerState,next:null}),r=h.hasEagerState?h.eagerState:e(r,h.action);else{var S={lane:k,action:h.action,hasEagerState:h.hasEagerState,eagerState:h.eagerState,next:null};s===null?(o=s=S,u=r):s=s.next=S,ve.lanes|=k,wn|=k}h=h.next}while(h!==null&&h!==i);s===null?u=r:s.next=o,vt(r,t.memoizedState)||(Je=!0),t.memoizedState=r,t.baseState=u,t.baseQueue=s,n.lastRenderedState=r}if(e=n.interleaved,e!==null){l=e;do i=l.lane,ve.lanes|=i,wn|=i,l=l.next;while(l!==e)}else l===null&&(n.lanes=0);return[t.memoizedState,n.dispatch]}function ru(e){var t=at(),n=t.queue;if(n===null)throw Error(v(311));n.lastRenderedReducer=e;var r=n.dispatch,l=n.pending,i=t.memoizedState;if(l!==null){n.pending=null;var u=l=l.next;do i=e(i,u.action),u=u.next;while(u!==l);vt(i,t.memoizedState)||(Je=!0),t.memoizedState=i,t.baseQueue===null&&(t.baseState=i),n.lastRenderedState=i}return[i,r]}function ms(){}function hs(e,t){var n=ve,r=at(),l=t(),i=!vt(r.memoizedState,l);if(i&&(r.memoizedState=l,Je=!0),r=r.queue,lu(gs.bind(null,n,r,e),[e]),r.getSnapshot!==t||i||ze!==null&&ze.memoizedState.tag&1){if(n.flags|=2048,Pr(9,ys.bind(null,n,r,l,t),void 0,null),Le===null)throw Error(v(349));(gn&30)!==0||vs(n,t,l)}return l}function vs(e,t,n){e.flags|=16384,e={getSnapshot:t,value:n},t=ve.updateQueue,t===null?(t={lastEffect:null,stores:null},ve.updateQueue=t,t.stores=[e]):(n=t.stores,n===null?t.stores=[e]:n.push(e))}function ys(e,t,n,r){t.value=n,t.getSnapshot=r,ws(t)&&ks(e)}function gs(e,t,n){return n(function(){ws(t)&&ks(e)})}function ws(e){var t=e.getSnapshot;e=e.value;try{var n=t();return!vt(e,n)}catch{return!0}}function ks(e){var t=Dt(e,1);t!==null&&St(t,e,1,-1)}function Ss(e){var t=zt();return typeof e=="function"&&(e=e()),t.memoizedState=t.baseState=e,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:jr,lastRenderedState:e},t.queue=e,e=e.dispatch=nf.bind(null,ve,e),[t.memoizedState,e]}function Pr(e,t,n,r){return e={tag:e,create:t,destroy:n,deps:r,next:null},t=ve.updateQueue,t===null?(t={lastEffect:null,stores:null},ve.updateQueue=t,t.lastEffect=e.next=e):(n=t.lastEffect,n===null?t.lastEffect=e.next=e:(r=n.next,n.next=e,e.next=r,t.lastEffect=e)),e}function xs(){return at().memoizedState}function Nl(e,t,n,r){var l=zt();ve.flags|=e,l.memoizedState=Pr(1|t,n,void 0,r===void 0?null:r)}function _l(e,t,n,r){var l=at();r=r===void 0?null:r;var i=void 0;if(_e!==null){var u=_e.memoizedState;if(i=u.destroy,r!==null&&bi(r,u.deps)){l.memoizedState=Pr(t,n,i,r);return}}ve.flags|=e,l.memoizedState=Pr(1|t,n,i,r)}function Cs(e,t){return Nl(8390656,8,e,t)}function lu(e,t){return _l(2048,8,e,t)}function Es(e,t){return _l(4,2,e,t)}function Ns(e,t){return _l(4,4,e,t)}function _s(e,t){if(typeof t=="function")return e=e(),t(e),function(){t(null)};if(t!=null)return e=e(),t.current=e,function(){t.current=null}}function js(e,t,n){return n=n!=null?n.concat([e]):null,_l(4,4,_s.bind(null,t,e),n)}function iu(){}function Ps(e,t){var n=at();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&bi(t,r[1])?r[0]:(n.memoizedState=[e,t],e)}function zs(e,t){var n=at();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&bi(t,r[1])?r[0]:(e=e(),n.memoizedState=[e,t],e)}function Ls(e,t,n){return(gn&21)===0?(e.baseState&&(e.baseState=!1,Je=!0),e.memoizedState=n):(vt(n,t)||(n=io(),ve.lanes|=n,wn|=n,e.baseState=!0),t)}function ef(e,t){var n=le;le=n!==0&&4>n?n:4,e(!0);var r=qi.transition;qi.transition={};try{e(!1),t()}finally{le=n,qi.transition=r}}function Ts(){return at().memoizedState}function tf(e,t,n){var r=un(e);if(n={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null},Rs(e))Ms(t,n);else if(n=as(e,t,n,r),n!==null){var l=Qe();St(n,e,r,l),Os(n,t,r)}}function nf(e,t,n){var r=un(e),l={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null};if(Rs(e))Ms(t,l);else{var i=e.alternate;if(e.lanes===0&&(i===null||i.lanes===0)&&(i=t.lastRenderedReducer,i!==null))try{var u=t.lastRenderedState,o=i(u,n);if(l.hasEagerState=!0,l.eagerState=o,vt(o,u)){var s=t.interleaved;s===null?(l.next=l,Ki(t)):(l.next=s.next,s.next=l),t.interleaved=l;return}}catch{}finally{}n=as(e,t,l,r),n!==null&&(l=Qe(),St(n,e,r,l),Os(n,t,r))}}function Rs(e){var t=e.alternate;return e===ve||t!==null&&t===ve}function Ms(e,t){Nr=El=!0;var n=e.pending;n===null?t.next=t:(t.next=n.next,n.next=t),e.pending=t}function Os(e,t,n){if((n&4194240)!==0){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,oi(e,n)}}var jl={readContext:st,useCallback:Ve,useContext:Ve,useEffect:Ve,useImperativeHandle:Ve,useInsertionEffect:Ve,useLayoutEffect:Ve,useMemo:Ve,useReducer:Ve,useRef:Ve,useState:Ve,useDebugValue:Ve,useDeferredValue:Ve,useTransition:Ve,useMutableSource:Ve,useSyncExternalStore:Ve,useId:Ve,unstable_isNewReconciler:!1},rf={readContext:st,useCallback:function(e,t){return zt().memoizedState=[e,t===void 0?null:t],e},useContext:st,useEffect:Cs,useImperativeHandle:function(e,t,n){return n=n!=null?n.concat([e]):null,Nl(4194308,4,_s.bind(null,t,e),n)},useLayoutEffect:function(e,t){return Nl(4194308,4,e,t)},useInsertionEffect:function(e,t){return Nl(4,2,e,t)},useMemo:function(e,t){var n=zt();return t=t===void 0?null:t,e=e(),n.memoizedState=[e,t],e},useReducer:function(e,t,n){var r=zt();return t=n!==void 0?n(t):t,r.memoizedState=r.baseState=t,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:e,lastRenderedState:t},r.queue=e,e=e.dispatch=tf.bind(null,ve,e),[r.memoizedState,e]},useRef:function(e){var t=zt();return e={current:e},t.memoizedState=e},useState:Ss,useDebugValue:iu,useDeferredValue:function(e){return zt().memoizedState=e},useTransition:function(){var e=Ss(!1),t=e[0];return e=ef.bind(null,e[1]),zt().memoizedState=e,[t,e]},useMutableSource:function(){},useSyncExternalStore:function(e,t,n){var r=ve,l=zt();if(pe){if(n===void 0)throw Error(v(407));n=n()}else{if(n=t(),Le===null)throw Error(v(349));(gn&30)!==0||vs(r,t,n)}l.memoizedState=n;var i={value:n,getSnapshot:t};return l.queue=i,Cs(gs.bind(null,r,i,e),[e]),r.flags|=2048,Pr(9,ys.bind(null,r,i,n,t),void 0,null),n},useId:function(){var e=zt(),t=Le.identifierPrefix;if(pe){var n=It,r=Ft;n=(r&~(1<<32-ht(r)-1)).toString(32)+n,t=":"+t+"R"+n,n=_r++,0<n&&(t+="H"+n.toString(32)),t+=":"}else n=bc++,t=":"+t+"r"+n.toString(32)+":";return e.memoizedState=t},unstable_isNewReconciler:!1},lf={readContext:st,useCallback:Ps,useContext:st,useEffect:lu,useImperativeHandle:js,useInsertionEffect:Es,useLayoutEffect:Ns,useMemo:zs,useReducer:nu,useRef:xs,useState:function(){return nu(jr)},useDebugValue:iu,useDeferredValue:function(e){var t=at();return Ls(t,_e.memoizedState,e)},useTransition:function(){var e=nu(jr)[0],t=at().memoizedState;return[e,t]},useMutableSource:ms,useSyncExternalStore:hs,useId:Ts,unstable_isNewReconciler:!1},uf={readContext:st,useCallback:Ps,useContext:st,useEffect:lu,useImperativeHandle:js,useInsertionEffect:Es,useLayoutEffect:Ns,useMemo:zs,useReducer:ru,useRef:xs,useState:function(){return ru(jr)},useDebugValue:iu,useDeferredValue:function(e){var t=at();return _e===null?t.memoizedState=e:Ls(t,_e.memoizedState,e)},useTransition:function(){var e=ru(jr)[0],t=at().memoizedState;return[e,t]},useMutableSource:ms,useSyncExternalStore:hs,useId:Ts,unstable_isNewReconciler:!1};function gt(e,t){if(e&&e.defaultProps){t=N({},t),e=e.defaultProps;for(var n in e)t[n]===void 0&&(t[n]=e[n]);return t}return t}function uu(e,t,n,r){t=e.memoizedState,n=n(r,t),n=n==null?t:N({},t,n),e.memoizedState=n,e.lanes===0&&(e.updateQueue.baseState=n)}var Pl={isMounted:function(e){return(e=e._reactInternals)?fn(e)===e:!1},enqueueSetState:function(e,t,n){e=e._reactInternals;var r=Qe(),l=un(e),i=Ut(r,l);i.payload=t,n!=null&&(i.callback=n),t=tn(e,i,l),t!==null&&(St(t,e,l,r),kl(t,e,l))},enqueueReplaceState:function(e,t,n){e=e._reactInternals;var r=Qe(),l=un(e),i=Ut(r,l);i.tag=1,i.payload=t,n!=null&&(i.callback=n),t=tn(e,i,l),t!==null&&(St(t,e,l,r),kl(t,e,l))},enqueueForceUpdate:function(e,t){e=e._reactInternals;var n=Qe(),r=un(e),l=Ut(n,r);l.tag=2,t!=null&&(l.callback=t),t=tn(e,l,r),t!==null&&(St(t,e,r,n),kl(t,e,r))}};function Fs(e,t,n,r,l,i,u){return e=e.stateNode,typeof e.shouldComponentUpdate=="function"?e.shouldComponentUpdate(r,i,u):t.prototype&&t.prototype.isPureReactComponent?!mr(n,r)||!mr(l,i):!0}function Is(e,t,n){var r=!1,l=qt,i=t.contextType;return typeof i=="object"&&i!==null?i=st(i):(l=Ge(t)?pn:Ae.current,r=t.contextTypes,i=(r=r!=null)?Un(e,l):qt),t=new t(n,i),e.memoizedState=t.state!==null&&t.state!==void 0?t.state:null,t.updater=Pl,e.stateNode=t,t._reactInternals=e,r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=l,e.__reactInternalMemoizedMaskedChildContext=i),t}function Ds(e,t,n,r){e=t.state,typeof t.componentWillReceiveProps=="function"&&t.componentWillReceiveProps(n,r),typeof t.UNSAFE_componentWillReceiveProps=="function"&&t.UNSAFE_componentWillReceiveProps(n,r),t.state!==e&&Pl.enqueueReplaceState(t,t.state,null)}function ou(e,t,n,r){var l=e.stateNode;l.props=n,l.state=e.memoizedState,l.refs={},Yi(e);var i=t.contextType;typeof i=="object"&&i!==null?l.context=st(i):(i=Ge(t)?pn:Ae.current,l.context=Un(e,i)),l.state=e.memoizedState,i=t.getDerivedStateFromProps,typeof i=="function"&&(uu(e,t,i,n),l.state=e.memoizedState),typeof t.getDerivedStateFromProps=="function"||typeof l.getSnapshotBeforeUpdate=="function"||typeof l.UNSAFE_componentWillMount!="function"&&typeof l.componentWillMount!="function"||(t=l.state,typeof l.componentWillMount=="function"&&l.componentWillMount(),typeof l.UNSAFE_componentWillMount=="function"&&l.UNSAFE_componentWillMount(),t!==l.state&&Pl.enqueueReplaceState(l,l.state,null),Sl(e,n,l,r),l.state=e.memoizedState),typeof l.componentDidMount=="function"&&(e.flags|=4194308)}function Kn(e,t){try{var n="",r=t;do n+=Y(r),r=r.return;while(r);var l=n}catch(i){l=`
-Error generating stack: `+i.message+`
-`+i.stack}return{value:e,source:t,stack:l,digest:null}}function su(e,t,n){return{value:e,source:null,stack:n??null,digest:t??null}}function au(e,t){try{console.error(t.value)}catch(n){setTimeout(function(){throw n})}}var of=typeof WeakMap=="function"?WeakMap:Map;function Us(e,t,n){n=Ut(-1,n),n.tag=3,n.payload={element:null};var r=t.value;return n.callback=function(){Fl||(Fl=!0,Eu=r),au(e,t)},n}function As(e,t,n){n=Ut(-1,n),n.tag=3;var r=e.type.getDerivedStateFromError;if(typeof r=="function"){var l=t.value;n.payload=function(){return r(l)},n.callback=function(){au(e,t)}}var i=e.stateNode;return i!==null&&typeof i.componentDidCatch=="function"&&(n.callback=function(){au(e,t),typeof r!="function"&&(rn===null?rn=new Set([this]):rn.add(this));var u=t.stack;this.componentDidCatch(t.value,{componentStack:u!==null?u:""})}),n}function Vs(e,t,n){var r=e.pingCache;if(r===null){r=e.pingCache=new of;var l=new Set;r.set(t,l)}else l=r.get(t),l===void 0&&(l=new Set,r.set(t,l));l.has(n)||(l.add(n),e=Sf.bind(null,e,t,n),t.then(e,e))}function Bs(e){do{var t;if((t=e.tag===13)&&(t=e.memoizedState,t=t!==null?t.dehydrated!==null:!0),t)return e;e=e.return}while(e!==null);return null}function $s(e,t,n,r,l){return(e.mode&1)===0?(e===t?e.flags|=65536:(e.flags|=128,n.flags|=131072,n.flags&=-52805,n.tag===1&&(n.alternate===null?n.tag=17:(t=Ut(-1,1),t.tag=2,tn(n,t,1))),n.lanes|=1),e):(e.flags|=65536,e.lanes=l,e)}var sf=ee.ReactCurrentOwner,Je=!1;function We(e,t,n,r){t.child=e===null?ss(t,null,n,r):$n(t,e.child,n,r)}function Hs(e,t,n,r,l){n=n.render;var i=t.ref;return Wn(t,l),r=eu(e,t,n,r,i,l),n=tu(),e!==null&&!Je?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~l,At(e,t,l)):(pe&&n&&Di(t),t.flags|=1,We(e,t,r,l),t.child)}function Ws(e,t,n,r,l){if(e===null){var i=n.type;return typeof i=="function"&&!Tu(i)&&i.defaultProps===void 0&&n.compare===null&&n.defaultProps===void 0?(t.tag=15,t.type=i,Qs(e,t,i,r,l)):(e=Bl(n.type,null,r,t,t.mode,l),e.ref=t.ref,e.return=t,t.child=e)}if(i=e.child,(e.lanes&l)===0){var u=i.memoizedProps;if(n=n.compare,n=n!==null?n:mr,n(u,r)&&e.ref===t.ref)return At(e,t,l)}return t.flags|=1,e=sn(i,r),e.ref=t.ref,e.return=t,t.child=e}function Qs(e,t,n,r,l){if(e!==null){var i=e.memoizedProps;if(mr(i,r)&&e.ref===t.ref)if(Je=!1,t.pendingProps=r=i,(e.lanes&l)!==0)(e.flags&131072)!==0&&(Je=!0);else return t.lanes=e.lanes,At(e,t,l)}return cu(e,t,n,r,l)}function Ks(e,t,n){var r=t.pendingProps,l=r.children,i=e!==null?e.memoizedState:null;if(r.mode==="hidden")if((t.mode&1)===0)t.memoizedState={baseLanes:0,cachePool:null,transitions:null},ae(Xn,lt),lt|=n;else{if((n&1073741824)===0)return e=i!==null?i.baseLanes|n:n,t.lanes=t.childLanes=1073741824,t.memoizedState={baseLanes:e,cachePool:null,transitions:null},t.updateQueue=null,ae(Xn,lt),lt|=e,null;t.memoizedState={baseLanes:0,cachePool:null,transitions:null},r=i!==null?i.baseLanes:n,ae(Xn,lt),lt|=r}else i!==null?(r=i.baseLanes|n,t.memoizedState=null):r=n,ae(Xn,lt),lt|=r;return We(e,t,l,n),t.child}function Ys(e,t){var n=t.ref;(e===null&&n!==null||e!==null&&e.ref!==n)&&(t.flags|=512,t.flags|=2097152)}function cu(e,t,n,r,l){var i=Ge(n)?pn:Ae.current;return i=Un(t,i),Wn(t,l),n=eu(e,t,n,r,i,l),r=tu(),e!==null&&!Je?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~l,At(e,t,l)):(pe&&r&&Di(t),t.flags|=1,We(e,t,n,l),t.child)}function Xs(e,t,n,r,l){if(Ge(n)){var i=!0;dl(t)}else i=!1;if(Wn(t,l),t.stateNode===null)Ll(e,t),Is(t,n,r),ou(t,n,r,l),r=!0;else if(e===null){var u=t.stateNode,o=t.memoizedProps;u.props=o;var s=u.context,h=n.contextType;typeof h=="object"&&h!==null?h=st(h):(h=Ge(n)?pn:Ae.current,h=Un(t,h));var k=n.getDerivedStateFromProps,S=typeof k=="function"||typeof u.getSnapshotBeforeUpdate=="function";S||typeof u.UNSAFE_componentWillReceiveProps!="function"&&typeof u.componentWillReceiveProps!="function"||(o!==r||s!==h)&&Ds(t,u,r,h),en=!1;var w=t.memoizedState;u.state=w,Sl(t,r,u,l),s=t.memoizedState,o!==r||w!==s||Xe.current||en?(typeof k=="function"&&(uu(t,n,k,r),s=t.memoizedState),(o=en||Fs(t,n,o,r,w,s,h))?(S||typeof u.UNSAFE_componentWillMount!="function"&&typeof u.componentWillMount!="function"||(typeof u.componentWillMount=="function"&&u.componentWillMount(),typeof u.UNSAFE_componentWillMount=="function"&&u.UNSAFE_componentWillMount()),typeof u.componentDidMount=="function"&&(t.flags|=4194308)):(typeof u.componentDidMount=="function"&&(t.flags|=4194308),t.memoizedProps=r,t.memoizedState=s),u.props=r,u.state=s,u.context=h,r=o):(typeof u.componentDidMount=="function"&&(t.flags|=4194308),r=!1)}else{u=t.stateNode,cs(e,t),o=t.memoizedProps,h=t.type===t.elementType?o:gt(t.type,o),u.props=h,S=t.pendingProps,w=u.context,s=n.contextType,typeof s=="object"&&s!==null?s=st(s):(s=Ge(n)?pn:Ae.current,s=Un(t,s));var E=n.getDerivedStateFromProps;(k=typeof E=="function"||typeof u.getSnapshotBeforeUpdate=="function")||typeof u.UNSAFE_componentWillReceiveProps!="function"&&typeof u.componentWillReceiveProps!="function"||(o!==S||w!==s)&&Ds(t,u,r,s),en=!1,w=t.memoizedState,u.state=w,Sl(t,r,u,l);var j=t.memoizedState;o!==S||w!==j||Xe.current||en?(typeof E=="function"&&(uu(t,n,E,r),j=t.memoizedState),(h=en||Fs(t,n,h,r,w,j,s)||!1)?(k||typeof u.UNSAFE_componentWillUpdate!="function"&&typeof u.componentWillUpdate!="function"||(typeof u.componentWillUpdate=="function"&&u.componentWillUpdate(r,j,s),typeof u.UNSAFE_componentWillUpdate=="function"&&u.UNSAFE_componentWillUpdate(r,j,s)),typeof u.componentDidUpdate=="function"&&(t.flags|=4),typeof u.getSnapshotBeforeUpdate=="function"&&(t.flags|=1024)):(typeof u.componentDidUpdate!="function"||o===e.memoizedProps&&w===e.memoizedState||(t.flags|=4),typeof u.getSnapshotBeforeUpdate!="function"||o===e.memoizedProps&&w===e.memoizedState||(t.flags|=1024),t.memoizedProps=r,t.memoizedState=j),u.props=r,u.state=j,u.context=s,r=h):(typeof u.componentDidUpdate!="function"||o===e.memoizedProps&&w===e.memoizedState||(t.flags|=4),typeof u.getSnapshotBeforeUpdate!="function"||o===e.memoizedProps&&w===e.memoizedState||(t.flags|=1024),r=!1)}return fu(e,t,n,r,i,l)}function fu(e,t,n,r,l,i){Ys(e,t);var u=(t.flags&128)!==0;if(!r&&!u)return l&&bo(t,n,!1),At(e,t,i);r=t.stateNode,sf.current=t;var o=u&&typeof n.getDerivedStateFromError!="function"?null:r.render();return t.flags|=1,e!==null&&u?(t.child=$n(t,e.child,null,i),t.child=$n(t,null,o,i)):We(e,t,o,i),t.memoizedState=r.state,l&&bo(t,n,!0),t.child}function Gs(e){var t=e.stateNode;t.pendingContext?Zo(e,t.pendingContext,t.pendingContext!==t.context):t.context&&Zo(e,t.context,!1),Xi(e,t.containerInfo)}function Js(e,t,n,r,l){return Bn(),Bi(l),t.flags|=256,We(e,t,n,r),t.child}var du={dehydrated:null,treeContext:null,retryLane:0};function pu(e){return{baseLanes:e,cachePool:null,transitions:null}}function Zs(e,t,n){var r=t.pendingProps,l=he.current,i=!1,u=(t.flags&128)!==0,o;if((o=u)||(o=e!==null&&e.memoizedState===null?!1:(l&2)!==0),o?(i=!0,t.flags&=-129):(e===null||e.memoizedState!==null)&&(l|=1),ae(he,l&1),e===null)return Vi(t),e=t.memoizedState,e!==null&&(e=e.dehydrated,e!==null)?((t.mode&1)===0?t.lanes=1:e.data==="$!"?t.lanes=8:t.lanes=1073741824,null):(u=r.children,e=r.fallback,i?(r=t.mode,i=t.child,u={mode:"hidden",children:u},(r&1)===0&&i!==null?(i.childLanes=0,i.pendingProps=u):i=$l(u,r,0,null),e=Cn(e,r,n,null),i.return=t,e.return=t,i.sibling=e,t.child=i,t.child.memoizedState=pu(n),t.memoizedState=du,e):mu(t,u));if(l=e.memoizedState,l!==null&&(o=l.dehydrated,o!==null))return af(e,t,u,r,o,l,n);if(i){i=r.fallback,u=t.mode,l=e.child,o=l.sibling;var s={mode:"hidden",children:r.children};return(u&1)===0&&t.child!==l?(r=t.child,r.childLanes=0,r.pendingProps=s,t.deletions=null):(r=sn(l,s),r.subtreeFlags=l.subtreeFlags&14680064),o!==null?i=sn(o,i):(i=Cn(i,u,n,null),i.flags|=2),i.return=t,r.return=t,r.sibling=i,t.child=r,r=i,i=t.child,u=e.child.memoizedState,u=u===null?pu(n):{baseLanes:u.baseLanes|n,cachePool:null,transitions:u.transitions},i.memoizedState=u,i.childLanes=e.childLanes&~n,t.memoizedState=du,r}return i=e.child,e=i.sibling,r=sn(i,{mode:"visible",children:r.children}),(t.mode&1)===0&&(r.lanes=n),r.return=t,r.sibling=null,e!==null&&(n=t.deletions,n===null?(t.deletions=[e],t.flags|=16):n.push(e)),t.child=r,t.memoizedState=null,r}function mu(e,t){return t=$l({mode:"visible",children:t},e.mode,0,null),t.return=e,e.child=t}function zl(e,t,n,r){return r!==null&&Bi(r),$n(t,e.child,null,n),e=mu(t,t.pendingProps.children),e.flags|=2,t.memoizedState=null,e}function af(e,t,n,r,l,i,u){if(n)return t.flags&256?(t.flags&=-257,r=su(Error(v(422))),zl(e,t,u,r)):t.memoizedState!==null?(t.child=e.child,t.flags|=128,null):(i=r.fallback,l=t.mode,r=$l({mode:"visible",children:r.children},l,0,null),i=Cn(i,l,u,null),i.flags|=2,r.return=t,i.return=t,r.sibling=i,t.child=r,(t.mode&1)!==0&&$n(t,e.child,null,u),t.child.memoizedState=pu(u),t.memoizedState=du,i);if((t.mode&1)===0)return zl(e,t,u,null);if(l.data==="$!"){if(r=l.nextSibling&&l.nextSibling.dataset,r)var o=r.dgst;return r=o,i=Error(v(419)),r=su(i,r,void 0),zl(e,t,u,r)}if(o=(u&e.childLanes)!==0,Je||o){if(r=Le,r!==null){switch(u&-u){case 4:l=2;break;case 16:l=8;break;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:l=32;break;case 536870912:l=268435456;break;default:l=0}l=(l&(r.suspendedLanes|u))!==0?0:l,l!==0&&l!==i.retryLane&&(i.retryLane=l,Dt(e,l),St(r,e,l,-1))}return Lu(),r=su(Error(v(421))),zl(e,t,u,r)}return l.data==="$?"?(t.flags|=128,t.child=e.child,t=xf.bind(null,e),l._reactRetry=t,null):(e=i.treeContext,rt=Jt(l.nextSibling),nt=t,pe=!0,yt=null,e!==null&&(ut[ot++]=Ft,ut[ot++]=It,ut[ot++]=mn,Ft=e.id,It=e.overflow,mn=t),t=mu(t,r.children),t.flags|=4096,t)}function qs(e,t,n){e.lanes|=t;var r=e.alternate;r!==null&&(r.lanes|=t),Qi(e.return,t,n)}function hu(e,t,n,r,l){var i=e.memoizedState;i===null?e.memoizedState={isBackwards:t,rendering:null,renderingStartTime:0,last:r,tail:n,tailMode:l}:(i.isBackwards=t,i.rendering=null,i.renderingStartTime=0,i.last=r,i.tail=n,i.tailMode=l)}function bs(e,t,n){var r=t.pendingProps,l=r.revealOrder,i=r.tail;if(We(e,t,r.children,n),r=he.current,(r&2)!==0)r=r&1|2,t.flags|=128;else{if(e!==null&&(e.flags&128)!==0)e:for(e=t.child;e!==null;){if(e.tag===13)e.memoizedState!==null&&qs(e,n,t);else if(e.tag===19)qs(e,n,t);else if(e.child!==null){e.child.return=e,e=e.child;continue}if(e===t)break e;for(;e.sibling===null;){if(e.return===null||e.return===t)break e;e=e.return}e.sibling.return=e.return,e=e.sibling}r&=1}if(ae(he,r),(t.mode&1)===0)t.memoizedState=null;else switch(l){case"forwards":for(n=t.child,l=null;n!==null;)e=n.alternate,e!==null&&xl(e)===null&&(l=n),n=n.sibling;n=l,n===null?(l=t.child,t.child=null):(l=n.sibling,n.sibling=null),hu(t,!1,l,n,i);break;case"backwards":for(n=null,l=t.child,t.child=null;l!==null;){if(e=l.alternate,e!==null&&xl(e)===null){t.child=l;break}e=l.sibling,l.sibling=n,n=l,l=e}hu(t,!0,n,null,i);break;case"together":hu(t,!1,null,null,void 0);break;default:t.memoizedState=null}return t.child}function Ll(e,t){(t.mode&1)===0&&e!==null&&(e.alternate=null,t.alternate=null,t.flags|=2)}function At(e,t,n){if(e!==null&&(t.dependencies=e.dependencies),wn|=t.lanes,(n&t.childLanes)===0)return null;if(e!==null&&t.child!==e.child)throw Error(v(153));if(t.child!==null){for(e=t.child,n=sn(e,e.pendingProps),t.child=n,n.return=t;e.sibling!==null;)e=e.sibling,n=n.sibling=sn(e,e.pendingProps),n.return=t;n.sibling=null}return t.child}function cf(e,t,n){switch(t.tag){case 3:Gs(t),Bn();break;case 5:ps(t);break;case 1:Ge(t.type)&&dl(t);break;case 4:Xi(t,t.stateNode.containerInfo);break;case 10:var r=t.type._context,l=t.memoizedProps.value;ae(gl,r._currentValue),r._currentValue=l;break;case 13:if(r=t.memoizedState,r!==null)return r.dehydrated!==null?(ae(he,he.current&1),t.flags|=128,null):(n&t.child.childLanes)!==0?Zs(e,t,n):(ae(he,he.current&1),e=At(e,t,n),e!==null?e.sibling:null);ae(he,he.current&1);break;case 19:if(r=(n&t.childLanes)!==0,(e.flags&128)!==0){if(r)return bs(e,t,n);t.flags|=128}if(l=t.memoizedState,l!==null&&(l.rendering=null,l.tail=null,l.lastEffect=null),ae(he,he.current),r)break;return null;case 22:case 23:return t.lanes=0,Ks(e,t,n)}return At(e,t,n)}var ea,vu,ta,na;ea=function(e,t){for(var n=t.child;n!==null;){if(n.tag===5||n.tag===6)e.appendChild(n.stateNode);else if(n.tag!==4&&n.child!==null){n.child.return=n,n=n.child;continue}if(n===t)break;for(;n.sibling===null;){if(n.return===null||n.return===t)return;n=n.return}n.sibling.return=n.return,n=n.sibling}},vu=function(){},ta=function(e,t,n,r){var l=e.memoizedProps;if(l!==r){e=t.stateNode,yn(Pt.current);var i=null;switch(n){case"input":l=Nn(e,l),r=Nn(e,r),i=[];break;case"select":l=N({},l,{value:void 0}),r=N({},r,{value:void 0}),i=[];break;case"textarea":l=cn(e,l),r=cn(e,r),i=[];break;default:typeof l.onClick!="function"&&typeof r.onClick=="function"&&(e.onclick=al)}Ht(n,r);var u;n=null;for(h in l)if(!r.hasOwnProperty(h)&&l.hasOwnProperty(h)&&l[h]!=null)if(h==="style"){var o=l[h];for(u in o)o.hasOwnProperty(u)&&(n||(n={}),n[u]="")}else h!=="dangerouslySetInnerHTML"&&h!=="children"&&h!=="suppressContentEditableWarning"&&h!=="suppressHydrationWarning"&&h!=="autoFocus"&&(W.hasOwnProperty(h)?i||(i=[]):(i=i||[]).push(h,null));for(h in r){var s=r[h];if(o=l!=null?l[h]:void 0,r.hasOwnProperty(h)&&s!==o&&(s!=null||o!=null))if(h==="style")if(o){for(u in o)!o.hasOwnProperty(u)||s&&s.hasOwnProperty(u)||(n||(n={}),n[u]="");for(u in s)s.hasOwnProperty(u)&&o[u]!==s[u]&&(n||(n={}),n[u]=s[u])}else n||(i||(i=[]),i.push(h,n)),n=s;else h==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,o=o?o.__html:void 0,s!=null&&o!==s&&(i=i||[]).push(h,s)):h==="children"?typeof s!="string"&&typeof s!="number"||(i=i||[]).push(h,""+s):h!=="suppressContentEditableWarning"&&h!=="suppressHydrationWarning"&&(W.hasOwnProperty(h)?(s!=null&&h==="onScroll"&&fe("scroll",e),i||o===s||(i=[])):(i=i||[]).push(h,s))}n&&(i=i||[]).push("style",n);var h=i;(t.updateQueue=h)&&(t.flags|=4)}},na=function(e,t,n,r){n!==r&&(t.flags|=4)};function zr(e,t){if(!pe)switch(e.tailMode){case"hidden":t=e.tail;for(var n=null;t!==null;)t.alternate!==null&&(n=t),t=t.sibling;n===null?e.tail=null:n.sibling=null;break;case"collapsed":n=e.tail;for(var r=null;n!==null;)n.alternate!==null&&(r=n),n=n.sibling;r===null?t||e.tail===null?e.tail=null:e.tail.sibling=null:r.sibling=null}}function Be(e){var t=e.alternate!==null&&e.alternate.child===e.child,n=0,r=0;if(t)for(var l=e.child;l!==null;)n|=l.lanes|l.childLanes,r|=l.subtreeFlags&14680064,r|=l.flags&14680064,l.return=e,l=l.sibling;else for(l=e.child;l!==null;)n|=l.lanes|l.childLanes,r|=l.subtreeFlags,r|=l.flags,l.return=e,l=l.sibling;return e.subtreeFlags|=r,e.childLanes=n,t}function ff(e,t,n){var r=t.pendingProps;switch(Ui(t),t.tag){case 2:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return Be(t),null;case 1:return Ge(t.type)&&fl(),Be(t),null;case 3:return r=t.stateNode,Qn(),de(Xe),de(Ae),Zi(),r.pendingContext&&(r.context=r.pendingContext,r.pendingContext=null),(e===null||e.child===null)&&(vl(t)?t.flags|=4:e===null||e.memoizedState.isDehydrated&&(t.flags&256)===0||(t.flags|=1024,yt!==null&&(ju(yt),yt=null))),vu(e,t),Be(t),null;case 5:Gi(t);var l=yn(Er.current);if(n=t.type,e!==null&&t.stateNode!=null)ta(e,t,n,r,l),e.ref!==t.ref&&(t.flags|=512,t.flags|=2097152);else{if(!r){if(t.stateNode===null)throw Error(v(166));return Be(t),null}if(e=yn(Pt.current),vl(t)){r=t.stateNode,n=t.type;var i=t.memoizedProps;switch(r[jt]=t,r[wr]=i,e=(t.mode&1)!==0,n){case"dialog":fe("cancel",r),fe("close",r);break;case"iframe":case"object":case"embed":fe("load",r);break;case"video":case"audio":for(l=0;l<vr.length;l++)fe(vr[l],r);break;case"source":fe("error",r);break;case"img":case"image":case"link":fe("error",r),fe("load",r);break;case"details":fe("toggle",r);break;case"input":Dr(r,i),fe("invalid",r);break;case"select":r._wrapperState={wasMultiple:!!i.multiple},fe("invalid",r);break;case"textarea":$t(r,i),fe("invalid",r)}Ht(n,i),l=null;for(var u in i)if(i.hasOwnProperty(u)){var o=i[u];u==="children"?typeof o=="string"?r.textContent!==o&&(i.suppressHydrationWarning!==!0&&sl(r.textContent,o,e),l=["children",o]):typeof o=="number"&&r.textContent!==""+o&&(i.suppressHydrationWarning!==!0&&sl(r.textContent,o,e),l=["children",""+o]):W.hasOwnProperty(u)&&o!=null&&u==="onScroll"&&fe("scroll",r)}switch(n){case"input":En(r),Et(r,i,!0);break;case"textarea":En(r),Zn(r);break;case"select":case"option":break;default:typeof i.onClick=="function"&&(r.onclick=al)}r=l,t.updateQueue=r,r!==null&&(t.flags|=4)}else{u=l.nodeType===9?l:l.ownerDocument,e==="http://www.w3.org/1999/xhtml"&&(e=a(n)),e==="http://www.w3.org/1999/xhtml"?n==="script"?(e=u.createElement("div"),e.innerHTML="<script><\/script>",e=e.removeChild(e.firstChild)):typeof r.is=="string"?e=u.createElement(n,{is:r.is}):(e=u.createElement(n),n==="select"&&(u=e,r.multiple?u.multiple=!0:r.size&&(u.size=r.size))):e=u.createElementNS(e,n),e[jt]=t,e[wr]=r,ea(e,t,!1,!1),t.stateNode=e;e:{switch(u=_n(n,r),n){case"dialog":fe("cancel",e),fe("close",e),l=r;break;case"iframe":case"object":case"embed":fe("load",e),l=r;break;case"video":case"audio":for(l=0;l<vr.length;l++)fe(vr[l],e);l=r;break;case"source":fe("error",e),l=r;break;case"img":case"image":case"link":fe("error",e),fe("load",e),l=r;break;case"details":fe("toggle",e),l=r;break;case"input":Dr(e,r),l=Nn(e,r),fe("invalid",e);break;case"option":l=r;break;case"select":e._wrapperState={wasMultiple:!!r.multiple},l=N({},r,{value:void 0}),fe("invalid",e);break;case"textarea":$t(e,r),l=cn(e,r),fe("invalid",e);break;default:l=r}Ht(n,l),o=l;for(i in o)if(o.hasOwnProperty(i)){var s=o[i];i==="style"?Br(e,s):i==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,s!=null&&ke(e,s)):i==="children"?typeof s=="string"?(n!=="textarea"||s!=="")&&mt(e,s):typeof s=="number"&&mt(e,""+s):i!=="suppressContentEditableWarning"&&i!=="suppressHydrationWarning"&&i!=="autoFocus"&&(W.hasOwnProperty(i)?s!=null&&i==="onScroll"&&fe("scroll",e):s!=null&&Ke(e,i,s,u))}switch(n){case"input":En(e),Et(e,r,!1);break;case"textarea":En(e),Zn(e);break;case"option":r.value!=null&&e.setAttribute("value",""+Z(r.value));break;case"select":e.multiple=!!r.multiple,i=r.value,i!=null?Nt(e,!!r.multiple,i,!1):r.defaultValue!=null&&Nt(e,!!r.multiple,r.defaultValue,!0);break;default:typeof l.onClick=="function"&&(e.onclick=al)}switch(n){case"button":case"input":case"select":case"textarea":r=!!r.autoFocus;break e;case"img":r=!0;break e;default:r=!1}}r&&(t.flags|=4)}t.ref!==null&&(t.flags|=512,t.flags|=2097152)}return Be(t),null;case 6:if(e&&t.stateNode!=null)na(e,t,e.memoizedProps,r);else{if(typeof r!="string"&&t.stateNode===null)throw Error(v(166));if(n=yn(Er.current),yn(Pt.current),vl(t)){if(r=t.stateNode,n=t.memoizedProps,r[jt]=t,(i=r.nodeValue!==n)&&(e=nt,e!==null))switch(e.tag){case 3:sl(r.nodeValue,n,(e.mode&1)!==0);break;case 5:e.memoizedProps.suppressHydrationWarning!==!0&&sl(r.nodeValue,n,(e.mode&1)!==0)}i&&(t.flags|=4)}else r=(n.nodeType===9?n:n.ownerDocument).createTextNode(r),r[jt]=t,t.stateNode=r}return Be(t),null;case 13:if(de(he),r=t.memoizedState,e===null||e.memoizedState!==null&&e.memoizedState.dehydrated!==null){if(pe&&rt!==null&&(t.mode&1)!==0&&(t.flags&128)===0)is(),Bn(),t.flags|=98560,i=!1;else if(i=vl(t),r!==null&&r.dehydrated!==null){if(e===null){if(!i)throw Error(v(318));if(i=t.memoizedState,i=i!==null?i.dehydrated:null,!i)throw Error(v(317));i[jt]=t}else Bn(),(t.flags&128)===0&&(t.memoizedState=null),t.flags|=4;Be(t),i=!1}else yt!==null&&(ju(yt),yt=null),i=!0;if(!i)return t.flags&65536?t:null}return(t.flags&128)!==0?(t.lanes=n,t):(r=r!==null,r!==(e!==null&&e.memoizedState!==null)&&r&&(t.child.flags|=8192,(t.mode&1)!==0&&(e===null||(he.current&1)!==0?je===0&&(je=3):Lu())),t.updateQueue!==null&&(t.flags|=4),Be(t),null);case 4:return Qn(),vu(e,t),e===null&&yr(t.stateNode.containerInfo),Be(t),null;case 10:return Wi(t.type._context),Be(t),null;case 17:return Ge(t.type)&&fl(),Be(t),null;case 19:if(de(he),i=t.memoizedState,i===null)return Be(t),null;if(r=(t.flags&128)!==0,u=i.rendering,u===null)if(r)zr(i,!1);else{if(je!==0||e!==null&&(e.flags&128)!==0)for(e=t.child;e!==null;){if(u=xl(e),u!==null){for(t.flags|=128,zr(i,!1),r=u.updateQueue,r!==null&&(t.updateQueue=r,t.flags|=4),t.subtreeFlags=0,r=n,n=t.child;n!==null;)i=n,e=r,i.flags&=14680066,u=i.alternate,u===null?(i.childLanes=0,i.lanes=e,i.child=null,i.subtreeFlags=0,i.memoizedProps=null,i.memoizedState=null,i.updateQueue=null,i.dependencies=null,i.stateNode=null):(i.childLanes=u.childLanes,i.lanes=u.lanes,i.child=u.child,i.subtreeFlags=0,i.deletions=null,i.memoizedProps=u.memoizedProps,i.memoizedState=u.memoizedState,i.updateQueue=u.updateQueue,i.type=u.type,e=u.dependencies,i.dependencies=e===null?null:{lanes:e.lanes,firstContext:e.firstContext}),n=n.sibling;return ae(he,he.current&1|2),t.child}e=e.sibling}i.tail!==null&&Se()>Gn&&(t.flags|=128,r=!0,zr(i,!1),t.lanes=4194304)}else{if(!r)if(e=xl(u),e!==null){if(t.flags|=128,r=!0,n=e.updateQueue,n!==null&&(t.updateQueue=n,t.flags|=4),zr(i,!0),i.tail===null&&i.tailMode==="hidden"&&!u.alternate&&!pe)return Be(t),null}else 2*Se()-i.renderingStartTime>Gn&&n!==1073741824&&(t.flags|=128,r=!0,zr(i,!1),t.lanes=4194304);i.isBackwards?(u.sibling=t.child,t.child=u):(n=i.last,n!==null?n.sibling=u:t.child=u,i.last=u)}return i.tail!==null?(t=i.tail,i.rendering=t,i.tail=t.sibling,i.renderingStartTime=Se(),t.sibling=null,n=he.current,ae(he,r?n&1|2:n&1),t):(Be(t),null);case 22:case 23:return zu(),r=t.memoizedState!==null,e!==null&&e.memoizedState!==null!==r&&(t.flags|=8192),r&&(t.mode&1)!==0?(lt&1073741824)!==0&&(Be(t),t.subtreeFlags&6&&(t.flags|=8192)):Be(t),null;case 24:return null;case 25:return null}throw Error(v(156,t.tag))}function df(e,t){switch(Ui(t),t.tag){case 1:return Ge(t.type)&&fl(),e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 3:return Qn(),de(Xe),de(Ae),Zi(),e=t.flags,(e&65536)!==0&&(e&128)===0?(t.flags=e&-65537|128,t):null;case 5:return Gi(t),null;case 13:if(de(he),e=t.memoizedState,e!==null&&e.dehydrated!==null){if(t.alternate===null)throw Error(v(340));Bn()}return e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 19:return de(he),null;case 4:return Qn(),null;case 10:return Wi(t.type._context),null;case 22:case 23:return zu(),null;case 24:return null;default:return null}}var Tl=!1,$e=!1,pf=typeof WeakSet=="function"?WeakSet:Set,_=null;function Yn(e,t){var n=e.ref;if(n!==null)if(typeof n=="function")try{n(null)}catch(r){ge(e,t,r)}else n.current=null}function yu(e,t,n){try{n()}catch(r){ge(e,t,r)}}var ra=!1;function mf(e,t){if(zi=Zr,e=Fo(),Si(e)){if("selectionStart"in e)var n={start:e.selectionStart,end:e.selectionEnd};else e:{n=(n=e.ownerDocument)&&n.defaultView||window;var r=n.getSelection&&n.getSelection();if(r&&r.rangeCount!==0){n=r.anchorNode;var l=r.anchorOffset,i=r.focusNode;r=r.focusOffset;try{n.nodeType,i.nodeType}catch{n=null;break e}var u=0,o=-1,s=-1,h=0,k=0,S=e,w=null;t:for(;;){for(var E;S!==n||l!==0&&S.nodeType!==3||(o=u+l),S!==i||r!==0&&S.nodeType!==3||(s=u+r),S.nodeType===3&&(u+=S.nodeValue.length),(E=S.firstChild)!==null;)w=S,S=E;for(;;){if(S===e)break t;if(w===n&&++h===l&&(o=u),w===i&&++k===r&&(s=u),(E=S.nextSibling)!==null)break;S=w,w=S.parentNode}S=E}n=o===-1||s===-1?null:{start:o,end:s}}else n=null}n=n||{start:0,end:0}}else n=null;for(Li={focusedElem:e,selectionRange:n},Zr=!1,_=t;_!==null;)if(t=_,e=t.child,(t.subtreeFlags&1028)!==0&&e!==null)e.return=t,_=e;else for(;_!==null;){t=_;try{var j=t.alternate;if((t.flags&1024)!==0)switch(t.tag){case 0:case 11:case 15:break;case 1:if(j!==null){var P=j.memoizedProps,xe=j.memoizedState,p=t.stateNode,c=p.getSnapshotBeforeUpdate(t.elementType===t.type?P:gt(t.type,P),xe);p.__reactInternalSnapshotBeforeUpdate=c}break;case 3:var m=t.stateNode.containerInfo;m.nodeType===1?m.textContent="":m.nodeType===9&&m.documentElement&&m.removeChild(m.documentElement);break;case 5:case 6:case 4:case 17:break;default:throw Error(v(163))}}catch(x){ge(t,t.return,x)}if(e=t.sibling,e!==null){e.return=t.return,_=e;break}_=t.return}return j=ra,ra=!1,j}function Lr(e,t,n){var r=t.updateQueue;if(r=r!==null?r.lastEffect:null,r!==null){var l=r=r.next;do{if((l.tag&e)===e){var i=l.destroy;l.destroy=void 0,i!==void 0&&yu(t,n,i)}l=l.next}while(l!==r)}}function Rl(e,t){if(t=t.updateQueue,t=t!==null?t.lastEffect:null,t!==null){var n=t=t.next;do{if((n.tag&e)===e){var r=n.create;n.destroy=r()}n=n.next}while(n!==t)}}function gu(e){var t=e.ref;if(t!==null){var n=e.stateNode;switch(e.tag){case 5:e=n;break;default:e=n}typeof t=="function"?t(e):t.current=e}}function la(e){var t=e.alternate;t!==null&&(e.alternate=null,la(t)),e.child=null,e.deletions=null,e.sibling=null,e.tag===5&&(t=e.stateNode,t!==null&&(delete t[jt],delete t[wr],delete t[Oi],delete t[Gc],delete t[Jc])),e.stateNode=null,e.return=null,e.dependencies=null,e.memoizedProps=null,e.memoizedState=null,e.pendingProps=null,e.stateNode=null,e.updateQueue=null}function ia(e){return e.tag===5||e.tag===3||e.tag===4}function ua(e){e:for(;;){for(;e.sibling===null;){if(e.return===null||ia(e.return))return null;e=e.return}for(e.sibling.return=e.return,e=e.sibling;e.tag!==5&&e.tag!==6&&e.tag!==18;){if(e.flags&2||e.child===null||e.tag===4)continue e;e.child.return=e,e=e.child}if(!(e.flags&2))return e.stateNode}}function wu(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.nodeType===8?n.parentNode.insertBefore(e,t):n.insertBefore(e,t):(n.nodeType===8?(t=n.parentNode,t.insertBefore(e,n)):(t=n,t.appendChild(e)),n=n._reactRootContainer,n!=null||t.onclick!==null||(t.onclick=al));else if(r!==4&&(e=e.child,e!==null))for(wu(e,t,n),e=e.sibling;e!==null;)wu(e,t,n),e=e.sibling}function ku(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.insertBefore(e,t):n.appendChild(e);else if(r!==4&&(e=e.child,e!==null))for(ku(e,t,n),e=e.sibling;e!==null;)ku(e,t,n),e=e.sibling}var Oe=null,wt=!1;function nn(e,t,n){for(n=n.child;n!==null;)oa(e,t,n),n=n.sibling}function oa(e,t,n){if(_t&&typeof _t.onCommitFiberUnmount=="function")try{_t.onCommitFiberUnmount(Qr,n)}catch{}switch(n.tag){case 5:$e||Yn(n,t);case 6:var r=Oe,l=wt;Oe=null,nn(e,t,n),Oe=r,wt=l,Oe!==null&&(wt?(e=Oe,n=n.stateNode,e.nodeType===8?e.parentNode.removeChild(n):e.removeChild(n)):Oe.removeChild(n.stateNode));break;case 18:Oe!==null&&(wt?(e=Oe,n=n.stateNode,e.nodeType===8?Mi(e.parentNode,n):e.nodeType===1&&Mi(e,n),sr(e)):Mi(Oe,n.stateNode));break;case 4:r=Oe,l=wt,Oe=n.stateNode.containerInfo,wt=!0,nn(e,t,n),Oe=r,wt=l;break;case 0:case 11:case 14:case 15:if(!$e&&(r=n.updateQueue,r!==null&&(r=r.lastEffect,r!==null))){l=r=r.next;do{var i=l,u=i.destroy;i=i.tag,u!==void 0&&((i&2)!==0||(i&4)!==0)&&yu(n,t,u),l=l.next}while(l!==r)}nn(e,t,n);break;case 1:if(!$e&&(Yn(n,t),r=n.stateNode,typeof r.componentWillUnmount=="function"))try{r.props=n.memoizedProps,r.state=n.memoizedState,r.componentWillUnmount()}catch(o){ge(n,t,o)}nn(e,t,n);break;case 21:nn(e,t,n);break;case 22:n.mode&1?($e=(r=$e)||n.memoizedState!==null,nn(e,t,n),$e=r):nn(e,t,n);break;default:nn(e,t,n)}}function sa(e){var t=e.updateQueue;if(t!==null){e.updateQueue=null;var n=e.stateNode;n===null&&(n=e.stateNode=new pf),t.forEach(function(r){var l=Cf.bind(null,e,r);n.has(r)||(n.add(r),r.then(l,l))})}}function kt(e,t){var n=t.deletions;if(n!==null)for(var r=0;r<n.length;r++){var l=n[r];try{var i=e,u=t,o=u;e:for(;o!==null;){switch(o.tag){case 5:Oe=o.stateNode,wt=!1;break e;case 3:Oe=o.stateNode.containerInfo,wt=!0;break e;case 4:Oe=o.stateNode.containerInfo,wt=!0;break e}o=o.return}if(Oe===null)throw Error(v(160));oa(i,u,l),Oe=null,wt=!1;var s=l.alternate;s!==null&&(s.return=null),l.return=null}catch(h){ge(l,t,h)}}if(t.subtreeFlags&12854)for(t=t.child;t!==null;)aa(t,e),t=t.sibling}function aa(e,t){var n=e.alternate,r=e.flags;switch(e.tag){case 0:case 11:case 14:case 15:if(kt(t,e),Lt(e),r&4){try{Lr(3,e,e.return),Rl(3,e)}catch(P){ge(e,e.return,P)}try{Lr(5,e,e.return)}catch(P){ge(e,e.return,P)}}break;case 1:kt(t,e),Lt(e),r&512&&n!==null&&Yn(n,n.return);break;case 5:if(kt(t,e),Lt(e),r&512&&n!==null&&Yn(n,n.return),e.flags&32){var l=e.stateNode;try{mt(l,"")}catch(P){ge(e,e.return,P)}}if(r&4&&(l=e.stateNode,l!=null)){var i=e.memoizedProps,u=n!==null?n.memoizedProps:i,o=e.type,s=e.updateQueue;if(e.updateQueue=null,s!==null)try{o==="input"&&i.type==="radio"&&i.name!=null&&Ur(l,i),_n(o,u);var h=_n(o,i);for(u=0;u<s.length;u+=2){var k=s[u],S=s[u+1];k==="style"?Br(l,S):k==="dangerouslySetInnerHTML"?ke(l,S):k==="children"?mt(l,S):Ke(l,k,S,h)}switch(o){case"input":Bt(l,i);break;case"textarea":Ar(l,i);break;case"select":var w=l._wrapperState.wasMultiple;l._wrapperState.wasMultiple=!!i.multiple;var E=i.value;E!=null?Nt(l,!!i.multiple,E,!1):w!==!!i.multiple&&(i.defaultValue!=null?Nt(l,!!i.multiple,i.defaultValue,!0):Nt(l,!!i.multiple,i.multiple?[]:"",!1))}l[wr]=i}catch(P){ge(e,e.return,P)}}break;case 6:if(kt(t,e),Lt(e),r&4){if(e.stateNode===null)throw Error(v(162));l=e.stateNode,i=e.memoizedProps;try{l.nodeValue=i}catch(P){ge(e,e.return,P)}}break;case 3:if(kt(t,e),Lt(e),r&4&&n!==null&&n.memoizedState.isDehydrated)try{sr(t.containerInfo)}catch(P){ge(e,e.return,P)}break;case 4:kt(t,e),Lt(e);break;case 13:kt(t,e),Lt(e),l=e.child,l.flags&8192&&(i=l.memoizedState!==null,l.stateNode.isHidden=i,!i||l.alternate!==null&&l.alternate.memoizedState!==null||(Cu=Se())),r&4&&sa(e);break;case 22:if(k=n!==null&&n.memoizedState!==null,e.mode&1?($e=(h=$e)||k,kt(t,e),$e=h):kt(t,e),Lt(e),r&8192){if(h=e.memoizedState!==null,(e.stateNode.isHidden=h)&&!k&&(e.mode&1)!==0)for(_=e,k=e.child;k!==null;){for(S=_=k;_!==null;){switch(w=_,E=w.child,w.tag){case 0:case 11:case 14:case 15:Lr(4,w,w.return);break;case 1:Yn(w,w.return);var j=w.stateNode;if(typeof j.componentWillUnmount=="function"){r=w,n=w.return;try{t=r,j.props=t.memoizedProps,j.state=t.memoizedState,j.componentWillUnmount()}catch(P){ge(r,n,P)}}break;case 5:Yn(w,w.return);break;case 22:if(w.memoizedState!==null){da(S);continue}}E!==null?(E.return=w,_=E):da(S)}k=k.sibling}e:for(k=null,S=e;;){if(S.tag===5){if(k===null){k=S;try{l=S.stateNode,h?(i=l.style,typeof i.setProperty=="function"?i.setProperty("display","none","important"):i.display="none"):(o=S.stateNode,s=S.memoizedProps.style,u=s!=null&&s.hasOwnProperty("display")?s.display:null,o.style.display=qn("display",u))}catch(P){ge(e,e.return,P)}}}else if(S.tag===6){if(k===null)try{S.stateNode.nodeValue=h?"":S.memoizedProps}catch(P){ge(e,e.return,P)}}else if((S.tag!==22&&S.tag!==23||S.memoizedState===null||S===e)&&S.child!==null){S.child.return=S,S=S.child;continue}if(S===e)break e;for(;S.sibling===null;){if(S.return===null||S.return===e)break e;k===S&&(k=null),S=S.return}k===S&&(k=null),S.sibling.return=S.return,S=S.sibling}}break;case 19:kt(t,e),Lt(e),r&4&&sa(e);break;case 21:break;default:kt(t,e),Lt(e)}}function Lt(e){var t=e.flags;if(t&2){try{e:{for(var n=e.return;n!==null;){if(ia(n)){var r=n;break e}n=n.return}throw Error(v(160))}switch(r.tag){case 5:var l=r.stateNode;r.flags&32&&(mt(l,""),r.flags&=-33);var i=ua(e);ku(e,i,l);break;case 3:case 4:var u=r.stateNode.containerInfo,o=ua(e);wu(e,o,u);break;default:throw Error(v(161))}}catch(s){ge(e,e.return,s)}e.flags&=-3}t&4096&&(e.flags&=-4097)}function hf(e,t,n){_=e,ca(e)}function ca(e,t,n){for(var r=(e.mode&1)!==0;_!==null;){var l=_,i=l.child;if(l.tag===22&&r){var u=l.memoizedState!==null||Tl;if(!u){var o=l.alternate,s=o!==null&&o.memoizedState!==null||$e;o=Tl;var h=$e;if(Tl=u,($e=s)&&!h)for(_=l;_!==null;)u=_,s=u.child,u.tag===22&&u.memoizedState!==null?pa(l):s!==null?(s.return=u,_=s):pa(l);for(;i!==null;)_=i,ca(i),i=i.sibling;_=l,Tl=o,$e=h}fa(e)}else(l.subtreeFlags&8772)!==0&&i!==null?(i.return=l,_=i):fa(e)}}function fa(e){for(;_!==null;){var t=_;if((t.flags&8772)!==0){var n=t.alternate;try{if((t.flags&8772)!==0)switch(t.tag){case 0:case 11:case 15:$e||Rl(5,t);break;case 1:var r=t.stateNode;if(t.flags&4&&!$e)if(n===null)r.componentDidMount();else{var l=t.elementType===t.type?n.memoizedProps:gt(t.type,n.memoizedProps);r.componentDidUpdate(l,n.memoizedState,r.__reactInternalSnapshotBeforeUpdate)}var i=t.updateQueue;i!==null&&ds(t,i,r);break;case 3:var u=t.updateQueue;if(u!==null){if(n=null,t.child!==null)switch(t.child.tag){case 5:n=t.child.stateNode;break;case 1:n=t.child.stateNode}ds(t,u,n)}break;case 5:var o=t.stateNode;if(n===null&&t.flags&4){n=o;var s=t.memoizedProps;switch(t.type){case"button":case"input":case"select":case"textarea":s.autoFocus&&n.focus();break;case"img":s.src&&(n.src=s.src)}}break;case 6:break;case 4:break;case 12:break;case 13:if(t.memoizedState===null){var h=t.alternate;if(h!==null){var k=h.memoizedState;if(k!==null){var S=k.dehydrated;S!==null&&sr(S)}}}break;case 19:case 17:case 21:case 22:case 23:case 25:break;default:throw Error(v(163))}$e||t.flags&512&&gu(t)}catch(w){ge(t,t.return,w)}}if(t===e){_=null;break}if(n=t.sibling,n!==null){n.return=t.return,_=n;break}_=t.return}}function da(e){for(;_!==null;){var t=_;if(t===e){_=null;break}var n=t.sibling;if(n!==null){n.return=t.return,_=n;break}_=t.return}}function pa(e){for(;_!==null;){var t=_;try{switch(t.tag){case 0:case 11:case 15:var n=t.return;try{Rl(4,t)}catch(s){ge(t,n,s)}break;case 1:var r=t.stateNode;if(typeof r.componentDidMount=="function"){var l=t.return;try{r.componentDidMount()}catch(s){ge(t,l,s)}}var i=t.return;try{gu(t)}catch(s){ge(t,i,s)}break;case 5:var u=t.return;try{gu(t)}catch(s){ge(t,u,s)}}}catch(s){ge(t,t.return,s)}if(t===e){_=null;break}var o=t.sibling;if(o!==null){o.return=t.return,_=o;break}_=t.return}}var vf=Math.ceil,Ml=ee.ReactCurrentDispatcher,Su=ee.ReactCurrentOwner,ct=ee.ReactCurrentBatchConfig,J=0,Le=null,Ee=null,Fe=0,lt=0,Xn=Zt(0),je=0,Tr=null,wn=0,Ol=0,xu=0,Rr=null,Ze=null,Cu=0,Gn=1/0,Vt=null,Fl=!1,Eu=null,rn=null,Il=!1,ln=null,Dl=0,Mr=0,Nu=null,Ul=-1,Al=0;function Qe(){return(J&6)!==0?Se():Ul!==-1?Ul:Ul=Se()}function un(e){return(e.mode&1)===0?1:(J&2)!==0&&Fe!==0?Fe&-Fe:qc.transition!==null?(Al===0&&(Al=io()),Al):(e=le,e!==0||(e=window.event,e=e===void 0?16:ho(e.type)),e)}function St(e,t,n,r){if(50<Mr)throw Mr=0,Nu=null,Error(v(185));rr(e,n,r),((J&2)===0||e!==Le)&&(e===Le&&((J&2)===0&&(Ol|=n),je===4&&on(e,Fe)),qe(e,r),n===1&&J===0&&(t.mode&1)===0&&(Gn=Se()+500,pl&&bt()))}function qe(e,t){var n=e.callbackNode;qa(e,t);var r=Xr(e,e===Le?Fe:0);if(r===0)n!==null&&no(n),e.callbackNode=null,e.callbackPriority=0;else if(t=r&-r,e.callbackPriority!==t){if(n!=null&&no(n),t===1)e.tag===0?Zc(ha.bind(null,e)):es(ha.bind(null,e)),Yc(function(){(J&6)===0&&bt()}),n=null;else{switch(uo(r)){case 1:n=li;break;case 4:n=ro;break;case 16:n=Wr;break;case 536870912:n=lo;break;default:n=Wr}n=Ca(n,ma.bind(null,e))}e.callbackPriority=t,e.callbackNode=n}}function ma(e,t){if(Ul=-1,Al=0,(J&6)!==0)throw Error(v(327));var n=e.callbackNode;if(Jn()&&e.callbackNode!==n)return null;var r=Xr(e,e===Le?Fe:0);if(r===0)return null;if((r&30)!==0||(r&e.expiredLanes)!==0||t)t=Vl(e,r);else{t=r;var l=J;J|=2;var i=ya();(Le!==e||Fe!==t)&&(Vt=null,Gn=Se()+500,Sn(e,t));do try{wf();break}catch(o){va(e,o)}while(!0);Hi(),Ml.current=i,J=l,Ee!==null?t=0:(Le=null,Fe=0,t=je)}if(t!==0){if(t===2&&(l=ii(e),l!==0&&(r=l,t=_u(e,l))),t===1)throw n=Tr,Sn(e,0),on(e,r),qe(e,Se()),n;if(t===6)on(e,r);else{if(l=e.current.alternate,(r&30)===0&&!yf(l)&&(t=Vl(e,r),t===2&&(i=ii(e),i!==0&&(r=i,t=_u(e,i))),t===1))throw n=Tr,Sn(e,0),on(e,r),qe(e,Se()),n;switch(e.finishedWork=l,e.finishedLanes=r,t){case 0:case 1:throw Error(v(345));case 2:xn(e,Ze,Vt);break;case 3:if(on(e,r),(r&130023424)===r&&(t=Cu+500-Se(),10<t)){if(Xr(e,0)!==0)break;if(l=e.suspendedLanes,(l&r)!==r){Qe(),e.pingedLanes|=e.suspendedLanes&l;break}e.timeoutHandle=Ri(xn.bind(null,e,Ze,Vt),t);break}xn(e,Ze,Vt);break;case 4:if(on(e,r),(r&4194240)===r)break;for(t=e.eventTimes,l=-1;0<r;){var u=31-ht(r);i=1<<u,u=t[u],u>l&&(l=u),r&=~i}if(r=l,r=Se()-r,r=(120>r?120:480>r?480:1080>r?1080:1920>r?1920:3e3>r?3e3:4320>r?4320:1960*vf(r/1960))-r,10<r){e.timeoutHandle=Ri(xn.bind(null,e,Ze,Vt),r);break}xn(e,Ze,Vt);break;case 5:xn(e,Ze,Vt);break;default:throw Error(v(329))}}}return qe(e,Se()),e.callbackNode===n?ma.bind(null,e):null}function _u(e,t){var n=Rr;return e.current.memoizedState.isDehydrated&&(Sn(e,t).flags|=256),e=Vl(e,t),e!==2&&(t=Ze,Ze=n,t!==null&&ju(t)),e}function ju(e){Ze===null?Ze=e:Ze.push.apply(Ze,e)}function yf(e){for(var t=e;;){if(t.flags&16384){var n=t.updateQueue;if(n!==null&&(n=n.stores,n!==null))for(var r=0;r<n.length;r++){var l=n[r],i=l.getSnapshot;l=l.value;try{if(!vt(i(),l))return!1}catch{return!1}}}if(n=t.child,t.subtreeFlags&16384&&n!==null)n.return=t,t=n;else{if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return!0;t=t.return}t.sibling.return=t.return,t=t.sibling}}return!0}function on(e,t){for(t&=~xu,t&=~Ol,e.suspendedLanes|=t,e.pingedLanes&=~t,e=e.expirationTimes;0<t;){var n=31-ht(t),r=1<<n;e[n]=-1,t&=~r}}function ha(e){if((J&6)!==0)throw Error(v(327));Jn();var t=Xr(e,0);if((t&1)===0)return qe(e,Se()),null;var n=Vl(e,t);if(e.tag!==0&&n===2){var r=ii(e);r!==0&&(t=r,n=_u(e,r))}if(n===1)throw n=Tr,Sn(e,0),on(e,t),qe(e,Se()),n;if(n===6)throw Error(v(345));return e.finishedWork=e.current.alternate,e.finishedLanes=t,xn(e,Ze,Vt),qe(e,Se()),null}function Pu(e,t){var n=J;J|=1;try{return e(t)}finally{J=n,J===0&&(Gn=Se()+500,pl&&bt())}}function kn(e){ln!==null&&ln.tag===0&&(J&6)===0&&Jn();var t=J;J|=1;var n=ct.transition,r=le;try{if(ct.transition=null,le=1,e)return e()}finally{le=r,ct.transition=n,J=t,(J&6)===0&&bt()}}function zu(){lt=Xn.current,de(Xn)}function Sn(e,t){e.finishedWork=null,e.finishedLanes=0;var n=e.timeoutHandle;if(n!==-1&&(e.timeoutHandle=-1,Kc(n)),Ee!==null)for(n=Ee.return;n!==null;){var r=n;switch(Ui(r),r.tag){case 1:r=r.type.childContextTypes,r!=null&&fl();break;case 3:Qn(),de(Xe),de(Ae),Zi();break;case 5:Gi(r);break;case 4:Qn();break;case 13:de(he);break;case 19:de(he);break;case 10:Wi(r.type._context);break;case 22:case 23:zu()}n=n.return}if(Le=e,Ee=e=sn(e.current,null),Fe=lt=t,je=0,Tr=null,xu=Ol=wn=0,Ze=Rr=null,vn!==null){for(t=0;t<vn.length;t++)if(n=vn[t],r=n.interleaved,r!==null){n.interleaved=null;var l=r.next,i=n.pending;if(i!==null){var u=i.next;i.next=l,r.next=u}n.pending=r}vn=null}return e}function va(e,t){do{var n=Ee;try{if(Hi(),Cl.current=jl,El){for(var r=ve.memoizedState;r!==null;){var l=r.queue;l!==null&&(l.pending=null),r=r.next}El=!1}if(gn=0,ze=_e=ve=null,Nr=!1,_r=0,Su.current=null,n===null||n.return===null){je=1,Tr=t,Ee=null;break}e:{var i=e,u=n.return,o=n,s=t;if(t=Fe,o.flags|=32768,s!==null&&typeof s=="object"&&typeof s.then=="function"){var h=s,k=o,S=k.tag;if((k.mode&1)===0&&(S===0||S===11||S===15)){var w=k.alternate;w?(k.updateQueue=w.updateQueue,k.memoizedState=w.memoizedState,k.lanes=w.lanes):(k.updateQueue=null,k.memoizedState=null)}var E=Bs(u);if(E!==null){E.flags&=-257,$s(E,u,o,i,t),E.mode&1&&Vs(i,h,t),t=E,s=h;var j=t.updateQueue;if(j===null){var P=new Set;P.add(s),t.updateQueue=P}else j.add(s);break e}else{if((t&1)===0){Vs(i,h,t),Lu();break e}s=Error(v(426))}}else if(pe&&o.mode&1){var xe=Bs(u);if(xe!==null){(xe.flags&65536)===0&&(xe.flags|=256),$s(xe,u,o,i,t),Bi(Kn(s,o));break e}}i=s=Kn(s,o),je!==4&&(je=2),Rr===null?Rr=[i]:Rr.push(i),i=u;do{switch(i.tag){case 3:i.flags|=65536,t&=-t,i.lanes|=t;var p=Us(i,s,t);fs(i,p);break e;case 1:o=s;var c=i.type,m=i.stateNode;if((i.flags&128)===0&&(typeof c.getDerivedStateFromError=="function"||m!==null&&typeof m.componentDidCatch=="function"&&(rn===null||!rn.has(m)))){i.flags|=65536,t&=-t,i.lanes|=t;var x=As(i,o,t);fs(i,x);break e}}i=i.return}while(i!==null)}wa(n)}catch(z){t=z,Ee===n&&n!==null&&(Ee=n=n.return);continue}break}while(!0)}function ya(){var e=Ml.current;return Ml.current=jl,e===null?jl:e}function Lu(){(je===0||je===3||je===2)&&(je=4),Le===null||(wn&268435455)===0&&(Ol&268435455)===0||on(Le,Fe)}function Vl(e,t){var n=J;J|=2;var r=ya();(Le!==e||Fe!==t)&&(Vt=null,Sn(e,t));do try{gf();break}catch(l){va(e,l)}while(!0);if(Hi(),J=n,Ml.current=r,Ee!==null)throw Error(v(261));return Le=null,Fe=0,je}function gf(){for(;Ee!==null;)ga(Ee)}function wf(){for(;Ee!==null&&!Ha();)ga(Ee)}function ga(e){var t=xa(e.alternate,e,lt);e.memoizedProps=e.pendingProps,t===null?wa(e):Ee=t,Su.current=null}function wa(e){var t=e;do{var n=t.alternate;if(e=t.return,(t.flags&32768)===0){if(n=ff(n,t,lt),n!==null){Ee=n;return}}else{if(n=df(n,t),n!==null){n.flags&=32767,Ee=n;return}if(e!==null)e.flags|=32768,e.subtreeFlags=0,e.deletions=null;else{je=6,Ee=null;return}}if(t=t.sibling,t!==null){Ee=t;return}Ee=t=e}while(t!==null);je===0&&(je=5)}function xn(e,t,n){var r=le,l=ct.transition;try{ct.transition=null,le=1,kf(e,t,n,r)}finally{ct.transition=l,le=r}return null}function kf(e,t,n,r){do Jn();while(ln!==null);if((J&6)!==0)throw Error(v(327));n=e.finishedWork;var l=e.finishedLanes;if(n===null)return null;if(e.finishedWork=null,e.finishedLanes=0,n===e.current)throw Error(v(177));e.callbackNode=null,e.callbackPriority=0;var i=n.lanes|n.childLanes;if(ba(e,i),e===Le&&(Ee=Le=null,Fe=0),(n.subtreeFlags&2064)===0&&(n.flags&2064)===0||Il||(Il=!0,Ca(Wr,function(){return Jn(),null})),i=(n.flags&15990)!==0,(n.subtreeFlags&15990)!==0||i){i=ct.transition,ct.transition=null;var u=le;le=1;var o=J;J|=4,Su.current=null,mf(e,n),aa(n,e),Ac(Li),Zr=!!zi,Li=zi=null,e.current=n,hf(n),Wa(),J=o,le=u,ct.transition=i}else e.current=n;if(Il&&(Il=!1,ln=e,Dl=l),i=e.pendingLanes,i===0&&(rn=null),Ya(n.stateNode),qe(e,Se()),t!==null)for(r=e.onRecoverableError,n=0;n<t.length;n++)l=t[n],r(l.value,{componentStack:l.stack,digest:l.digest});if(Fl)throw Fl=!1,e=Eu,Eu=null,e;return(Dl&1)!==0&&e.tag!==0&&Jn(),i=e.pendingLanes,(i&1)!==0?e===Nu?Mr++:(Mr=0,Nu=e):Mr=0,bt(),null}function Jn(){if(ln!==null){var e=uo(Dl),t=ct.transition,n=le;try{if(ct.transition=null,le=16>e?16:e,ln===null)var r=!1;else{if(e=ln,ln=null,Dl=0,(J&6)!==0)throw Error(v(331));var l=J;for(J|=4,_=e.current;_!==null;){var i=_,u=i.child;if((_.flags&16)!==0){var o=i.deletions;if(o!==null){for(var s=0;s<o.length;s++){var h=o[s];for(_=h;_!==null;){var k=_;switch(k.tag){case 0:case 11:case 15:Lr(8,k,i)}var S=k.child;if(S!==null)S.return=k,_=S;else for(;_!==null;){k=_;var w=k.sibling,E=k.return;if(la(k),k===h){_=null;break}if(w!==null){w.return=E,_=w;break}_=E}}}var j=i.alternate;if(j!==null){var P=j.child;if(P!==null){j.child=null;do{var xe=P.sibling;P.sibling=null,P=xe}while(P!==null)}}_=i}}if((i.subtreeFlags&2064)!==0&&u!==null)u.return=i,_=u;else e:for(;_!==null;){if(i=_,(i.flags&2048)!==0)switch(i.tag){case 0:case 11:case 15:Lr(9,i,i.return)}var p=i.sibling;if(p!==null){p.return=i.return,_=p;break e}_=i.return}}var c=e.current;for(_=c;_!==null;){u=_;var m=u.child;if((u.subtreeFlags&2064)!==0&&m!==null)m.return=u,_=m;else e:for(u=c;_!==null;){if(o=_,(o.flags&2048)!==0)try{switch(o.tag){case 0:case 11:case 15:Rl(9,o)}}catch(z){ge(o,o.return,z)}if(o===u){_=null;break e}var x=o.sibling;if(x!==null){x.return=o.return,_=x;break e}_=o.return}}if(J=l,bt(),_t&&typeof _t.onPostCommitFiberRoot=="function")try{_t.onPostCommitFiberRoot(Qr,e)}catch{}r=!0}return r}finally{le=n,ct.transition=t}}return!1}function ka(e,t,n){t=Kn(n,t),t=Us(e,t,1),e=tn(e,t,1),t=Qe(),e!==null&&(rr(e,1,t),qe(e,t))}function ge(e,t,n){if(e.tag===3)ka(e,e,n);else for(;t!==null;){if(t.tag===3){ka(t,e,n);break}else if(t.tag===1){var r=t.stateNode;if(typeof t.type.getDerivedStateFromError=="function"||typeof r.componentDidCatch=="function"&&(rn===null||!rn.has(r))){e=Kn(n,e),e=As(t,e,1),t=tn(t,e,1),e=Qe(),t!==null&&(rr(t,1,e),qe(t,e));break}}t=t.return}}function Sf(e,t,n){var r=e.pingCache;r!==null&&r.delete(t),t=Qe(),e.pingedLanes|=e.suspendedLanes&n,Le===e&&(Fe&n)===n&&(je===4||je===3&&(Fe&130023424)===Fe&&500>Se()-Cu?Sn(e,0):xu|=n),qe(e,t)}function Sa(e,t){t===0&&((e.mode&1)===0?t=1:(t=Yr,Yr<<=1,(Yr&130023424)===0&&(Yr=4194304)));var n=Qe();e=Dt(e,t),e!==null&&(rr(e,t,n),qe(e,n))}function xf(e){var t=e.memoizedState,n=0;t!==null&&(n=t.retryLane),Sa(e,n)}function Cf(e,t){var n=0;switch(e.tag){case 13:var r=e.stateNode,l=e.memoizedState;l!==null&&(n=l.retryLane);break;case 19:r=e.stateNode;break;default:throw Error(v(314))}r!==null&&r.delete(t),Sa(e,n)}var xa;xa=function(e,t,n){if(e!==null)if(e.memoizedProps!==t.pendingProps||Xe.current)Je=!0;else{if((e.lanes&n)===0&&(t.flags&128)===0)return Je=!1,cf(e,t,n);Je=(e.flags&131072)!==0}else Je=!1,pe&&(t.flags&1048576)!==0&&ts(t,hl,t.index);switch(t.lanes=0,t.tag){case 2:var r=t.type;Ll(e,t),e=t.pendingProps;var l=Un(t,Ae.current);Wn(t,n),l=eu(null,t,r,e,l,n);var i=tu();return t.flags|=1,typeof l=="object"&&l!==null&&typeof l.render=="function"&&l.$$typeof===void 0?(t.tag=1,t.memoizedState=null,t.updateQueue=null,Ge(r)?(i=!0,dl(t)):i=!1,t.memoizedState=l.state!==null&&l.state!==void 0?l.state:null,Yi(t),l.updater=Pl,t.stateNode=l,l._reactInternals=t,ou(t,r,e,n),t=fu(null,t,r,!0,i,n)):(t.tag=0,pe&&i&&Di(t),We(null,t,l,n),t=t.child),t;case 16:r=t.elementType;e:{switch(Ll(e,t),e=t.pendingProps,l=r._init,r=l(r._payload),t.type=r,l=t.tag=Nf(r),e=gt(r,e),l){case 0:t=cu(null,t,r,e,n);break e;case 1:t=Xs(null,t,r,e,n);break e;case 11:t=Hs(null,t,r,e,n);break e;case 14:t=Ws(null,t,r,gt(r.type,e),n);break e}throw Error(v(306,r,""))}return t;case 0:return r=t.type,l=t.pendingProps,l=t.elementType===r?l:gt(r,l),cu(e,t,r,l,n);case 1:return r=t.type,l=t.pendingProps,l=t.elementType===r?l:gt(r,l),Xs(e,t,r,l,n);case 3:e:{if(Gs(t),e===null)throw Error(v(387));r=t.pendingProps,i=t.memoizedState,l=i.element,cs(e,t),Sl(t,r,null,n);var u=t.memoizedState;if(r=u.element,i.isDehydrated)if(i={element:r,isDehydrated:!1,cache:u.cache,pendingSuspenseBoundaries:u.pendingSuspenseBoundaries,transitions:u.transitions},t.updateQueue.baseState=i,t.memoizedState=i,t.flags&256){l=Kn(Error(v(423)),t),t=Js(e,t,r,n,l);break e}else if(r!==l){l=Kn(Error(v(424)),t),t=Js(e,t,r,n,l);break e}else for(rt=Jt(t.stateNode.containerInfo.firstChild),nt=t,pe=!0,yt=null,n=ss(t,null,r,n),t.child=n;n;)n.flags=n.flags&-3|4096,n=n.sibling;else{if(Bn(),r===l){t=At(e,t,n);break e}We(e,t,r,n)}t=t.child}return t;case 5:return ps(t),e===null&&Vi(t),r=t.type,l=t.pendingProps,i=e!==null?e.memoizedProps:null,u=l.children,Ti(r,l)?u=null:i!==null&&Ti(r,i)&&(t.flags|=32),Ys(e,t),We(e,t,u,n),t.child;case 6:return e===null&&Vi(t),null;case 13:return Zs(e,t,n);case 4:return Xi(t,t.stateNode.containerInfo),r=t.pendingProps,e===null?t.child=$n(t,null,r,n):We(e,t,r,n),t.child;case 11:return r=t.type,l=t.pendingProps,l=t.elementType===r?l:gt(r,l),Hs(e,t,r,l,n);case 7:return We(e,t,t.pendingProps,n),t.child;case 8:return We(e,t,t.pendingProps.children,n),t.child;case 12:return We(e,t,t.pendingProps.children,n),t.child;case 10:e:{if(r=t.type._context,l=t.pendingProps,i=t.memoizedProps,u=l.value,ae(gl,r._currentValue),r._currentValue=u,i!==null)if(vt(i.value,u)){if(i.children===l.children&&!Xe.current){t=At(e,t,n);break e}}else for(i=t.child,i!==null&&(i.return=t);i!==null;){var o=i.dependencies;if(o!==null){u=i.child;for(var s=o.firstContext;s!==null;){if(s.context===r){if(i.tag===1){s=Ut(-1,n&-n),s.tag=2;var h=i.updateQueue;if(h!==null){h=h.shared;var k=h.pending;k===null?s.next=s:(s.next=k.next,k.next=s),h.pending=s}}i.lanes|=n,s=i.alternate,s!==null&&(s.lanes|=n),Qi(i.return,n,t),o.lanes|=n;break}s=s.next}}else if(i.tag===10)u=i.type===t.type?null:i.child;else if(i.tag===18){if(u=i.return,u===null)throw Error(v(341));u.lanes|=n,o=u.alternate,o!==null&&(o.lanes|=n),Qi(u,n,t),u=i.sibling}else u=i.child;if(u!==null)u.return=i;else for(u=i;u!==null;){if(u===t){u=null;break}if(i=u.sibling,i!==null){i.return=u.return,u=i;break}u=u.return}i=u}We(e,t,l.children,n),t=t.child}return t;case 9:return l=t.type,r=t.pendingProps.children,Wn(t,n),l=st(l),r=r(l),t.flags|=1,We(e,t,r,n),t.child;case 14:return r=t.type,l=gt(r,t.pendingProps),l=gt(r.type,l),Ws(e,t,r,l,n);case 15:return Qs(e,t,t.type,t.pendingProps,n);case 17:return r=t.type,l=t.pendingProps,l=t.elementType===r?l:gt(r,l),Ll(e,t),t.tag=1,Ge(r)?(e=!0,dl(t)):e=!1,Wn(t,n),Is(t,r,l),ou(t,r,l,n),fu(null,t,r,!0,e,n);case 19:return bs(e,t,n);case 22:return Ks(e,t,n)}throw Error(v(156,t.tag))};function Ca(e,t){return to(e,t)}function Ef(e,t,n,r){this.tag=e,this.key=n,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.ref=null,this.pendingProps=t,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=r,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function ft(e,t,n,r){return new Ef(e,t,n,r)}function Tu(e){return e=e.prototype,!(!e||!e.isReactComponent)}function Nf(e){if(typeof e=="function")return Tu(e)?1:0;if(e!=null){if(e=e.$$typeof,e===De)return 11;if(e===Ye)return 14}return 2}function sn(e,t){var n=e.alternate;return n===null?(n=ft(e.tag,t,e.key,e.mode),n.elementType=e.elementType,n.type=e.type,n.stateNode=e.stateNode,n.alternate=e,e.alternate=n):(n.pendingProps=t,n.type=e.type,n.flags=0,n.subtreeFlags=0,n.deletions=null),n.flags=e.flags&14680064,n.childLanes=e.childLanes,n.lanes=e.lanes,n.child=e.child,n.memoizedProps=e.memoizedProps,n.memoizedState=e.memoizedState,n.updateQueue=e.updateQueue,t=e.dependencies,n.dependencies=t===null?null:{lanes:t.lanes,firstContext:t.firstContext},n.sibling=e.sibling,n.index=e.index,n.ref=e.ref,n}function Bl(e,t,n,r,l,i){var u=2;if(r=e,typeof e=="function")Tu(e)&&(u=1);else if(typeof e=="string")u=5;else e:switch(e){case Pe:return Cn(n.children,l,i,t);case we:u=8,l|=8;break;case Ie:return e=ft(12,n,t,l|2),e.elementType=Ie,e.lanes=i,e;case He:return e=ft(13,n,t,l),e.elementType=He,e.lanes=i,e;case Me:return e=ft(19,n,t,l),e.elementType=Me,e.lanes=i,e;case ue:return $l(n,l,i,t);default:if(typeof e=="object"&&e!==null)switch(e.$$typeof){case it:u=10;break e;case xt:u=9;break e;case De:u=11;break e;case Ye:u=14;break e;case Ne:u=16,r=null;break e}throw Error(v(130,e==null?e:typeof e,""))}return t=ft(u,n,t,l),t.elementType=e,t.type=r,t.lanes=i,t}function Cn(e,t,n,r){return e=ft(7,e,r,t),e.lanes=n,e}function $l(e,t,n,r){return e=ft(22,e,r,t),e.elementType=ue,e.lanes=n,e.stateNode={isHidden:!1},e}function Ru(e,t,n){return e=ft(6,e,null,t),e.lanes=n,e}function Mu(e,t,n){return t=ft(4,e.children!==null?e.children:[],e.key,t),t.lanes=n,t.stateNode={containerInfo:e.containerInfo,pendingChildren:null,implementation:e.implementation},t}function _f(e,t,n,r,l){this.tag=t,this.containerInfo=e,this.finishedWork=this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.pendingContext=this.context=null,this.callbackPriority=0,this.eventTimes=ui(0),this.expirationTimes=ui(-1),this.entangledLanes=this.finishedLanes=this.mutableReadLanes=this.expiredLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=ui(0),this.identifierPrefix=r,this.onRecoverableError=l,this.mutableSourceEagerHydrationData=null}function Ou(e,t,n,r,l,i,u,o,s){return e=new _f(e,t,n,o,s),t===1?(t=1,i===!0&&(t|=8)):t=0,i=ft(3,null,null,t),e.current=i,i.stateNode=e,i.memoizedState={element:r,isDehydrated:n,cache:null,transitions:null,pendingSuspenseBoundaries:null},Yi(i),e}function jf(e,t,n){var r=3<arguments.length&&arguments[3]!==void 0?arguments[3]:null;return{$$typeof:T,key:r==null?null:""+r,children:e,containerInfo:t,implementation:n}}function Ea(e){if(!e)return qt;e=e._reactInternals;e:{if(fn(e)!==e||e.tag!==1)throw Error(v(170));var t=e;do{switch(t.tag){case 3:t=t.stateNode.context;break e;case 1:if(Ge(t.type)){t=t.stateNode.__reactInternalMemoizedMergedChildContext;break e}}t=t.return}while(t!==null);throw Error(v(171))}if(e.tag===1){var n=e.type;if(Ge(n))return qo(e,n,t)}return t}function Na(e,t,n,r,l,i,u,o,s){return e=Ou(n,r,!0,e,l,i,u,o,s),e.context=Ea(null),n=e.current,r=Qe(),l=un(n),i=Ut(r,l),i.callback=t??null,tn(n,i,l),e.current.lanes=l,rr(e,l,r),qe(e,r),e}function Hl(e,t,n,r){var l=t.current,i=Qe(),u=un(l);return n=Ea(n),t.context===null?t.context=n:t.pendingContext=n,t=Ut(i,u),t.payload={element:e},r=r===void 0?null:r,r!==null&&(t.callback=r),e=tn(l,t,u),e!==null&&(St(e,l,u,i),kl(e,l,u)),u}function Wl(e){if(e=e.current,!e.child)return null;switch(e.child.tag){case 5:return e.child.stateNode;default:return e.child.stateNode}}function _a(e,t){if(e=e.memoizedState,e!==null&&e.dehydrated!==null){var n=e.retryLane;e.retryLane=n!==0&&n<t?n:t}}function Fu(e,t){_a(e,t),(e=e.alternate)&&_a(e,t)}function Pf(){return null}var ja=typeof reportError=="function"?reportError:function(e){console.error(e)};function Iu(e){this._internalRoot=e}Ql.prototype.render=Iu.prototype.render=function(e){var t=this._internalRoot;if(t===null)throw Error(v(409));Hl(e,t,null,null)},Ql.prototype.unmount=Iu.prototype.unmount=function(){var e=this._internalRoot;if(e!==null){this._internalRoot=null;var t=e.containerInfo;kn(function(){Hl(null,e,null,null)}),t[Mt]=null}};function Ql(e){this._internalRoot=e}Ql.prototype.unstable_scheduleHydration=function(e){if(e){var t=ao();e={blockedOn:null,target:e,priority:t};for(var n=0;n<Yt.length&&t!==0&&t<Yt[n].priority;n++);Yt.splice(n,0,e),n===0&&po(e)}};function Du(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11)}function Kl(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11&&(e.nodeType!==8||e.nodeValue!==" react-mount-point-unstable "))}function Pa(){}function zf(e,t,n,r,l){if(l){if(typeof r=="function"){var i=r;r=function(){var h=Wl(u);i.call(h)}}var u=Na(t,r,e,0,null,!1,!1,"",Pa);return e._reactRootContainer=u,e[Mt]=u.current,yr(e.nodeType===8?e.parentNode:e),kn(),u}for(;l=e.lastChild;)e.removeChild(l);if(typeof r=="function"){var o=r;r=function(){var h=Wl(s);o.call(h)}}var s=Ou(e,0,!1,null,null,!1,!1,"",Pa);return e._reactRootContainer=s,e[Mt]=s.current,yr(e.nodeType===8?e.parentNode:e),kn(function(){Hl(t,s,n,r)}),s}function Yl(e,t,n,r,l){var i=n._reactRootContainer;if(i){var u=i;if(typeof l=="function"){var o=l;l=function(){var s=Wl(u);o.call(s)}}Hl(t,u,e,l)}else u=zf(n,t,e,l,r);return Wl(u)}oo=function(e){switch(e.tag){case 3:var t=e.stateNode;if(t.current.memoizedState.isDehydrated){var n=nr(t.pendingLanes);n!==0&&(oi(t,n|1),qe(t,Se()),(J&6)===0&&(Gn=Se()+500,bt()))}break;case 13:kn(function(){var r=Dt(e,1);if(r!==null){var l=Qe();St(r,e,1,l)}}),Fu(e,1)}},si=function(e){if(e.tag===13){var t=Dt(e,134217728);if(t!==null){var n=Qe();St(t,e,134217728,n)}Fu(e,134217728)}},so=function(e){if(e.tag===13){var t=un(e),n=Dt(e,t);if(n!==null){var r=Qe();St(n,e,t,r)}Fu(e,t)}},ao=function(){return le},co=function(e,t){var n=le;try{return le=e,t()}finally{le=n}},ei=function(e,t,n){switch(t){case"input":if(Bt(e,n),t=n.name,n.type==="radio"&&t!=null){for(n=e;n.parentNode;)n=n.parentNode;for(n=n.querySelectorAll("input[name="+JSON.stringify(""+t)+'][type="radio"]'),t=0;t<n.length;t++){var r=n[t];if(r!==e&&r.form===e.form){var l=cl(r);if(!l)throw Error(v(90));ye(r),Bt(r,l)}}}break;case"textarea":Ar(e,n);break;case"select":t=n.value,t!=null&&Nt(e,!!n.multiple,t,!1)}},Xu=Pu,Gu=kn;var Lf={usingClientEntryPoint:!1,Events:[kr,In,cl,Ku,Yu,Pu]},Or={findFiberByHostInstance:dn,bundleType:0,version:"18.3.1",rendererPackageName:"react-dom"},Tf={bundleType:Or.bundleType,version:Or.version,rendererPackageName:Or.rendererPackageName,rendererConfig:Or.rendererConfig,overrideHookState:null,overrideHookStateDeletePath:null,overrideHookStateRenamePath:null,overrideProps:null,overridePropsDeletePath:null,overridePropsRenamePath:null,setErrorHandler:null,setSuspenseHandler:null,scheduleUpdate:null,currentDispatcherRef:ee.ReactCurrentDispatcher,findHostInstanceByFiber:function(e){return e=bu(e),e===null?null:e.stateNode},findFiberByHostInstance:Or.findFiberByHostInstance||Pf,findHostInstancesForRefresh:null,scheduleRefresh:null,scheduleRoot:null,setRefreshHandler:null,getCurrentFiber:null,reconcilerVersion:"18.3.1-next-f1338f8080-20240426"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var Xl=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!Xl.isDisabled&&Xl.supportsFiber)try{Qr=Xl.inject(Tf),_t=Xl}catch{}}return be.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=Lf,be.createPortal=function(e,t){var n=2<arguments.length&&arguments[2]!==void 0?arguments[2]:null;if(!Du(t))throw Error(v(200));return jf(e,t,null,n)},be.createRoot=function(e,t){if(!Du(e))throw Error(v(299));var n=!1,r="",l=ja;return t!=null&&(t.unstable_strictMode===!0&&(n=!0),t.identifierPrefix!==void 0&&(r=t.identifierPrefix),t.onRecoverableError!==void 0&&(l=t.onRecoverableError)),t=Ou(e,1,!1,null,null,n,!1,r,l),e[Mt]=t.current,yr(e.nodeType===8?e.parentNode:e),new Iu(t)},be.findDOMNode=function(e){if(e==null)return null;if(e.nodeType===1)return e;var t=e._reactInternals;if(t===void 0)throw typeof e.render=="function"?Error(v(188)):(e=Object.keys(e).join(","),Error(v(268,e)));return e=bu(t),e=e===null?null:e.stateNode,e},be.flushSync=function(e){return kn(e)},be.hydrate=function(e,t,n){if(!Kl(t))throw Error(v(200));return Yl(null,e,t,!0,n)},be.hydrateRoot=function(e,t,n){if(!Du(e))throw Error(v(405));var r=n!=null&&n.hydratedSources||null,l=!1,i="",u=ja;if(n!=null&&(n.unstable_strictMode===!0&&(l=!0),n.identifierPrefix!==void 0&&(i=n.identifierPrefix),n.onRecoverableError!==void 0&&(u=n.onRecoverableError)),t=Na(t,null,e,1,n??null,l,!1,i,u),e[Mt]=t.current,yr(e),r)for(e=0;e<r.length;e++)n=r[e],l=n._getVersion,l=l(n._source),t.mutableSourceEagerHydrationData==null?t.mutableSourceEagerHydrationData=[n,l]:t.mutableSourceEagerHydrationData.push(n,l);return new Ql(t)},be.render=function(e,t,n){if(!Kl(t))throw Error(v(200));return Yl(null,e,t,!1,n)},be.unmountComponentAtNode=function(e){if(!Kl(e))throw Error(v(40));return e._reactRootContainer?(kn(function(){Yl(null,null,e,!1,function(){e._reactRootContainer=null,e[Mt]=null})}),!0):!1},be.unstable_batchedUpdates=Pu,be.unstable_renderSubtreeIntoContainer=function(e,t,n,r){if(!Kl(n))throw Error(v(200));if(e==null||e._reactInternals===void 0)throw Error(v(38));return Yl(e,t,n,!1,r)},be.version="18.3.1-next-f1338f8080-20240426",be}var Ia;function Af(){if(Ia)return Vu.exports;Ia=1;function L(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(L)}catch(B){console.error(B)}}return L(),Vu.exports=Uf(),Vu.exports}var Da;function Vf(){if(Da)return Gl;Da=1;var L=Af();return Gl.createRoot=L.createRoot,Gl.hydrateRoot=L.hydrateRoot,Gl}var Bf=Vf();const Hu=["C","C#","D","D#","E","F","F#","G","G#","A","A#","B"],Ir=[{id:"search",label:"search",type:"search"},{id:"tempo",label:"bpm",type:"range",min:40,max:220,step:1},{id:"sources",label:"playlist",type:"multiselect"},{id:"genres",label:"genre",type:"multiselect"},{id:"energy",label:"energy",type:"range",min:0,max:1,step:.05},{id:"danceability",label:"dance",type:"range",min:0,max:1,step:.05},{id:"valence",label:"valence",type:"range",min:0,max:1,step:.05},{id:"acousticness",label:"acoustic",type:"range",min:0,max:1,step:.05},{id:"instrumentalness",label:"instrumental",type:"range",min:0,max:1,step:.05},{id:"speechiness",label:"speech",type:"range",min:0,max:1,step:.05},{id:"liveness",label:"live",type:"range",min:0,max:1,step:.05},{id:"key",label:"key",type:"select",options:Hu},{id:"popularity",label:"popularity",type:"range",min:0,max:100,step:1}],$f=[{id:"name",label:"name"},{id:"artist",label:"artist"},{id:"album",label:"album"},{id:"sources",label:"playlists"},{id:"genres",label:"genres"},{id:"bpm",label:"bpm"},{id:"danceability",label:"dance"},{id:"energy",label:"energy"},{id:"valence",label:"valence"},{id:"acousticness",label:"acoustic"},{id:"instrumentalness",label:"instrumental"},{id:"speechiness",label:"speech"},{id:"liveness",label:"live"},{id:"popularity",label:"popularity"},{id:"key",label:"key"}],Hf=["name","artist","bpm","danceability","energy","valence"],Jl={search:"",tempo:[40,220],energy:[0,1],danceability:[0,1],valence:[0,1],acousticness:[0,1],instrumentalness:[0,1],speechiness:[0,1],liveness:[0,1],popularity:[0,100],key:"",sources:[],genres:[]};function Wf({options:L,selected:B,onChange:v,label:Ce,onClear:W}){const[G,ce]=b.useState(!1),[se,F]=b.useState(""),K=b.useRef(null);b.useEffect(()=>{const A=me=>{K.current&&!K.current.contains(me.target)&&ce(!1)};return document.addEventListener("mousedown",A),()=>document.removeEventListener("mousedown",A)},[]);const U=(L||[]).filter(A=>A.toLowerCase().includes(se.toLowerCase())),te=A=>{B.includes(A)?v(B.filter(me=>me!==A)):v([...B,A])};return f.jsxs("div",{className:"multiselect",ref:K,children:[f.jsx("button",{className:"multiselect-trigger",onClick:()=>ce(!G),children:B.length?`${B.length} selected`:`any ${Ce}`}),B.length>0&&W&&f.jsx("button",{className:"filter-clear",onClick:W,children:"x"}),G&&f.jsxs("div",{className:"multiselect-dropdown",children:[f.jsx("input",{type:"text",placeholder:"search...",value:se,onChange:A=>F(A.target.value),className:"multiselect-search",autoFocus:!0}),B.length>0&&f.jsx("button",{className:"multiselect-clear",onClick:()=>v([]),children:"clear all"}),f.jsxs("div",{className:"multiselect-options",children:[U.length===0&&f.jsx("div",{className:"multiselect-empty",children:"no options"}),U.map(A=>f.jsxs("label",{className:"multiselect-option",children:[f.jsx("input",{type:"checkbox",checked:B.includes(A),onChange:()=>te(A)}),A]},A))]})]})]})}function Qf({min:L,max:B,step:v,value:Ce,onChange:W,onClear:G,isActive:ce}){const[se,F]=Ce||[L,B];return f.jsxs("div",{className:"range-slider",children:[f.jsx("input",{type:"number",min:L,max:B,step:v,value:se,onChange:K=>W([Number(K.target.value),F]),placeholder:"min"}),f.jsx("span",{className:"range-dash",children:"-"}),f.jsx("input",{type:"number",min:L,max:B,step:v,value:F,onChange:K=>W([se,Number(K.target.value)]),placeholder:"max"}),ce&&G&&f.jsx("button",{className:"filter-clear",onClick:G,children:"x"})]})}function Kf(){var Zn;const[L,B]=b.useState([]),[v,Ce]=b.useState(null),[W,G]=b.useState([]),[ce,se]=b.useState([]),[F,K]=b.useState(()=>localStorage.getItem("musikk-sort")||"name"),[U,te]=b.useState(null),[A,me]=b.useState(!1),[Re,ie]=b.useState(!1),[ne,dt]=b.useState(!1),[I,Ke]=b.useState(()=>{const a=localStorage.getItem("musikk-columns");return a?JSON.parse(a):Hf}),[ee,et]=b.useState(null),[T,Pe]=b.useState(()=>{const a=localStorage.getItem("musikk-filters");return a?JSON.parse(a):Jl}),[we,Ie]=b.useState(new Set),[it,xt]=b.useState(!1),[De,He]=b.useState(null),[Me,Ye]=b.useState(null),[Ne,ue]=b.useState(!1),C=b.useRef(null),R=b.useCallback(async()=>{const a=new URLSearchParams;T.search&&a.set("search",T.search),T.tempo[0]>40&&a.set("tempo_min",T.tempo[0]),T.tempo[1]<220&&a.set("tempo_max",T.tempo[1]),T.energy[0]>0&&a.set("energy_min",T.energy[0]),T.energy[1]<1&&a.set("energy_max",T.energy[1]),T.danceability[0]>0&&a.set("danceability_min",T.danceability[0]),T.danceability[1]<1&&a.set("danceability_max",T.danceability[1]),T.valence[0]>0&&a.set("valence_min",T.valence[0]),T.valence[1]<1&&a.set("valence_max",T.valence[1]),T.key&&a.set("key",T.key),T.sources.length&&a.set("sources",T.sources.join(",")),T.genres.length&&a.set("genres",T.genres.join(",")),a.set("sort",F),a.set("limit","500");const V=await(await fetch("/api/tracks?"+a.toString())).json();B(V)},[T,F]);b.useEffect(()=>{R()},[R]),b.useEffect(()=>{localStorage.setItem("musikk-filters",JSON.stringify(T))},[T]),b.useEffect(()=>{localStorage.setItem("musikk-sort",F)},[F]),b.useEffect(()=>{localStorage.setItem("musikk-columns",JSON.stringify(I))},[I]),b.useEffect(()=>{fetch("/api/meta").then(a=>a.json()).then(a=>{Ce(a.stats),G(a.sources||[]),se(a.genres||[])})},[]),b.useEffect(()=>{const a=()=>{fetch("/api/player").then(V=>V.json()).then(et).catch(()=>{})};a();const g=setInterval(a,3e3);return()=>clearInterval(g)},[]);const N=b.useRef(null);b.useEffect(()=>{const a=g=>{(g.metaKey||g.ctrlKey)&&g.key==="a"&&(g.preventDefault(),Ie(new Set(L.map(V=>V.spotify_id)))),(g.metaKey||g.ctrlKey)&&g.key==="k"&&(g.preventDefault(),me(!0),setTimeout(()=>{var V;return(V=N.current)==null?void 0:V.focus()},100)),g.ctrlKey&&!g.metaKey&&g.key==="c"&&(g.preventDefault(),Pe(Jl)),g.key==="Escape"&&(A?me(!1):U?te(null):Re?ie(!1):(Ie(new Set),ue(!1)))};return window.addEventListener("keydown",a),()=>window.removeEventListener("keydown",a)},[L,we,A,U,Re]);const d=async(a,g)=>{a.stopPropagation(),await fetch(`/api/player/queue/${g}`,{method:"POST"})},y=async()=>{ee!=null&&ee.is_playing?await fetch("/api/player/pause",{method:"POST"}):await fetch("/api/player/resume",{method:"POST"})},$=async()=>{await fetch("/api/player/next",{method:"POST"})},H=async()=>{await fetch("/api/player/prev",{method:"POST"})},Y=a=>{var V;a.target.closest(".track-queue")||a.target.closest("button")||window.innerWidth<=600||!((V=C.current)!=null&&V.getBoundingClientRect())||(xt(!0),He({x:a.clientX,y:a.clientY}),Ye({x:a.clientX,y:a.clientY,width:0,height:0}),Ie(new Set))},X=a=>{var qn;if(!it||!De)return;const g=Math.min(a.clientX,De.x),V=Math.min(a.clientY,De.y),ke=Math.abs(a.clientX-De.x),mt=Math.abs(a.clientY-De.y);Ye({x:g,y:V,width:ke,height:mt});const Rt=(qn=C.current)==null?void 0:qn.querySelectorAll(".track"),Vr=new Set;Rt==null||Rt.forEach((Br,Zl)=>{var _n;const Ht=Br.getBoundingClientRect();Ht.left<g+ke&&Ht.right>g&&Ht.top<V+mt&&Ht.bottom>V&&Vr.add((_n=L[Zl])==null?void 0:_n.spotify_id)}),Ie(Vr)},re=()=>{xt(!1),Ye(null)},Z=()=>{Ie(new Set),ue(!1)},oe=a=>{const g=new Set(we);g.has(a)?g.delete(a):g.add(a),Ie(g)},Ue=()=>{Ie(new Set(L.map(a=>a.spotify_id)))},En=async()=>{for(const a of we)await fetch(`/api/player/queue/${a}`,{method:"POST"});Z()},ye=(a,g)=>{Pe(V=>({...V,[a]:g}))},Ct=a=>{Ir.find(V=>V.id===a)&&ye(a,Jl[a])},Nn=()=>{Pe(Jl)},Dr=a=>{I.includes(a)?Ke(I.filter(g=>g!==a)):Ke([...I,a])},Ur=async a=>{const V=await(await fetch("/api/tracks/"+a)).json();te(V)},Bt=a=>{if(!a)return"";try{return JSON.parse(a).join(", ")}catch{return a}},Et=a=>a!=null?Math.round(a*100)+"%":"-",Tt=a=>{const g=a.target;g.scrollWidth>g.clientWidth&&g.classList.add("truncated")},pt=a=>{a.target.classList.remove("truncated")},Nt=a=>{if(!a)return"-";const g=Math.floor(a/6e4),V=Math.floor(a%6e4/1e3);return`${g}:${String(V).padStart(2,"0")}`},cn=a=>{const g=Ir.find(V=>V.id===a);return g?g.type==="search"?!!T.search:g.type==="multiselect"?T[a].length>0:g.type==="select"?!!T[a]:g.type==="range"?T[a][0]>g.min||T[a][1]<g.max:!1:!1},$t=()=>Ir.filter(a=>cn(a.id)).length,Ar=(a,g=!1)=>{const V=cn(a.id);switch(a.type){case"search":return f.jsxs("div",{className:"filter-item",children:[f.jsx("label",{children:a.label}),f.jsx("input",{ref:g?N:null,type:"text",value:T.search,onChange:ke=>ye("search",ke.target.value),placeholder:""}),V&&f.jsx("button",{className:"filter-clear",onClick:()=>Ct("search"),children:"x"})]},a.id);case"range":return f.jsxs("div",{className:"filter-item",children:[f.jsx("label",{children:a.label}),f.jsx(Qf,{min:a.min,max:a.max,step:a.step,value:T[a.id],onChange:ke=>ye(a.id,ke),onClear:()=>Ct(a.id),isActive:V})]},a.id);case"multiselect":return f.jsxs("div",{className:"filter-item",children:[f.jsx("label",{children:a.label}),f.jsx(Wf,{options:a.id==="sources"?W:ce,selected:T[a.id],onChange:ke=>ye(a.id,ke),label:a.label,onClear:()=>Ct(a.id)})]},a.id);case"select":return f.jsxs("div",{className:"filter-item",children:[f.jsx("label",{children:a.label}),f.jsxs("select",{value:T.key,onChange:ke=>ye("key",ke.target.value),children:[f.jsx("option",{value:"",children:"any"}),a.options.map((ke,mt)=>f.jsx("option",{value:mt,children:ke},mt))]}),V&&f.jsx("button",{className:"filter-clear",onClick:()=>Ct("key"),children:"x"})]},a.id);default:return null}};return f.jsxs("div",{className:"app",children:[f.jsxs("header",{className:"app-header",children:[f.jsx("h1",{onClick:()=>dt(!ne),children:"musikk"}),f.jsxs("div",{className:"header-actions",children:[f.jsxs("button",{className:"header-btn",onClick:()=>me(!0),children:["filters ",$t()>0&&`(${$t()})`]}),f.jsx("button",{className:"header-btn",onClick:()=>ie(!Re),children:"cols"})]})]}),ne&&v&&f.jsxs("div",{className:"stats-panel",children:[f.jsxs("div",{children:[v.total_tracks," tracks"]}),v.last_sync&&f.jsxs("div",{children:["last sync: ",new Date(v.last_sync).toLocaleString()]})]}),Re&&f.jsx("div",{className:"column-picker",children:$f.map(a=>f.jsxs("label",{className:"picker-option",children:[f.jsx("input",{type:"checkbox",checked:I.includes(a.id),onChange:()=>Dr(a.id)}),a.label]},a.id))}),$t()>0&&f.jsxs("div",{className:"active-filters",children:[Ir.filter(a=>cn(a.id)).map(a=>f.jsxs("button",{className:"filter-chip",onClick:()=>Ct(a.id),children:[a.label," x"]},a.id)),$t()>1&&f.jsx("button",{className:"filter-chip",onClick:Nn,children:"clear all"})]}),A&&f.jsx("div",{className:"modal-overlay",onClick:()=>me(!1),onKeyDown:a=>a.key==="Enter"&&me(!1),children:f.jsxs("div",{className:"filter-modal",onClick:a=>a.stopPropagation(),onTouchStart:a=>{const g=a.touches[0];a.currentTarget.dataset.startY=g.clientY},onTouchEnd:a=>{const g=parseFloat(a.currentTarget.dataset.startY);a.changedTouches[0].clientY-g>80&&me(!1)},children:[f.jsx("div",{className:"modal-handle"}),f.jsxs("div",{className:"filter-modal-header",children:[f.jsx("h2",{children:"filters"}),$t()>0&&f.jsx("button",{className:"clear-all-btn",onClick:Nn,children:"clear all"})]}),f.jsx("div",{className:"filter-modal-content",children:Ir.map(a=>Ar(a,!0))})]})}),f.jsxs("div",{className:"header-row",children:[f.jsx("button",{className:"mobile-select-btn",onClick:()=>ue(!Ne),children:"+"}),I.includes("name")&&f.jsx("span",{className:`col-name ${F==="name"?"sorted":""}`,onClick:()=>K("name"),children:"name"}),I.includes("artist")&&f.jsx("span",{className:`col-artist ${F==="artists"?"sorted":""}`,onClick:()=>K("artists"),children:"artist"}),I.includes("album")&&f.jsx("span",{className:`col-album ${F==="album_name"?"sorted":""}`,onClick:()=>K("album_name"),children:"album"}),I.includes("sources")&&f.jsx("span",{className:"col-text",children:"playlists"}),I.includes("genres")&&f.jsx("span",{className:"col-text",children:"genres"}),I.includes("bpm")&&f.jsx("span",{className:`col-bpm ${F==="tempo"?"sorted":""}`,onClick:()=>K("tempo"),children:"bpm"}),I.includes("danceability")&&f.jsx("span",{className:`col-bar ${F==="danceability"?"sorted":""}`,onClick:()=>K("danceability"),children:"dance"}),I.includes("energy")&&f.jsx("span",{className:`col-bar ${F==="energy"?"sorted":""}`,onClick:()=>K("energy"),children:"energy"}),I.includes("valence")&&f.jsx("span",{className:`col-bar ${F==="valence"?"sorted":""}`,onClick:()=>K("valence"),children:"valence"}),I.includes("acousticness")&&f.jsx("span",{className:`col-bar ${F==="acousticness"?"sorted":""}`,onClick:()=>K("acousticness"),children:"acoustic"}),I.includes("instrumentalness")&&f.jsx("span",{className:`col-bar ${F==="instrumentalness"?"sorted":""}`,onClick:()=>K("instrumentalness"),children:"instr"}),I.includes("speechiness")&&f.jsx("span",{className:`col-bar ${F==="speechiness"?"sorted":""}`,onClick:()=>K("speechiness"),children:"speech"}),I.includes("liveness")&&f.jsx("span",{className:`col-bar ${F==="liveness"?"sorted":""}`,onClick:()=>K("liveness"),children:"live"}),I.includes("popularity")&&f.jsx("span",{className:`col-num ${F==="popularity"?"sorted":""}`,onClick:()=>K("popularity"),children:"pop"}),I.includes("key")&&f.jsx("span",{className:`col-key ${F==="key"?"sorted":""}`,onClick:()=>K("key"),children:"key"}),f.jsx("span",{className:"col-queue"})]}),(we.size>0||Ne)&&f.jsx("div",{className:"selection-bar",children:we.size>0?f.jsxs(f.Fragment,{children:[f.jsxs("span",{children:[we.size," selected"]}),f.jsx("button",{onClick:En,children:"queue all"}),f.jsx("button",{onClick:Ue,children:"all"}),f.jsx("button",{onClick:Z,children:"x"})]}):f.jsxs(f.Fragment,{children:[f.jsx("span",{children:"select tracks"}),f.jsx("button",{onClick:Ue,children:"all"}),f.jsx("button",{onClick:()=>ue(!1),children:"x"})]})}),f.jsx("ul",{className:"tracks",ref:C,onMouseDown:Y,onMouseMove:X,onMouseUp:re,onMouseLeave:re,children:L.map(a=>f.jsxs("li",{className:`track ${we.has(a.spotify_id)?"selected":""}`,onClick:()=>Ne?oe(a.spotify_id):!it&&Ur(a.spotify_id),children:[Ne&&f.jsx("input",{type:"checkbox",className:"track-checkbox",checked:we.has(a.spotify_id),onChange:()=>oe(a.spotify_id),onClick:g=>g.stopPropagation()}),f.jsxs("div",{className:"track-info",children:[I.includes("name")&&f.jsx("span",{className:"track-name",onMouseEnter:Tt,onMouseLeave:pt,children:a.name}),f.jsx("span",{className:"track-artist-mobile",children:Bt(a.artists)})]}),I.includes("artist")&&f.jsx("span",{className:"track-artists",onMouseEnter:Tt,onMouseLeave:pt,onClick:g=>{g.stopPropagation(),ye("search",Bt(a.artists))},children:Bt(a.artists)}),I.includes("album")&&f.jsx("span",{className:"track-album",onMouseEnter:Tt,onMouseLeave:pt,onClick:g=>{g.stopPropagation(),ye("search",a.album_name)},children:a.album_name||"-"}),I.includes("sources")&&f.jsx("span",{className:"track-text",onMouseEnter:Tt,onMouseLeave:pt,onClick:g=>g.stopPropagation(),children:a.sources?JSON.parse(a.sources).filter(g=>!g.startsWith("album:")).map((g,V,ke)=>f.jsxs("span",{children:[f.jsx("span",{className:"track-link",onClick:()=>ye("sources",[...new Set([...T.sources,g])]),children:g}),V<ke.length-1&&", "]},g)):"-"}),I.includes("genres")&&f.jsx("span",{className:"track-text",onMouseEnter:Tt,onMouseLeave:pt,onClick:g=>g.stopPropagation(),children:a.genres?JSON.parse(a.genres).map((g,V,ke)=>f.jsxs("span",{children:[f.jsx("span",{className:"track-link",onClick:()=>ye("genres",[...new Set([...T.genres,g])]),children:g}),V<ke.length-1&&", "]},g)):"-"}),I.includes("bpm")&&f.jsx("span",{className:"track-bpm",onClick:g=>{g.stopPropagation();const V=Math.round(a.tempo);ye("tempo",[V-.5,V+.49])},children:a.tempo?Math.round(a.tempo):"-"}),I.includes("danceability")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("danceability",[a.danceability,a.danceability])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.danceability||0)*100}%`}})}),I.includes("energy")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("energy",[a.energy,a.energy])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.energy||0)*100}%`}})}),I.includes("valence")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("valence",[a.valence,a.valence])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.valence||0)*100}%`}})}),I.includes("acousticness")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("acousticness",[a.acousticness,a.acousticness])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.acousticness||0)*100}%`}})}),I.includes("instrumentalness")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("instrumentalness",[a.instrumentalness,a.instrumentalness])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.instrumentalness||0)*100}%`}})}),I.includes("speechiness")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("speechiness",[a.speechiness,a.speechiness])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.speechiness||0)*100}%`}})}),I.includes("liveness")&&f.jsx("div",{className:"bar",onClick:g=>{g.stopPropagation(),ye("liveness",[a.liveness,a.liveness])},children:f.jsx("div",{className:"bar-fill",style:{width:`${(a.liveness||0)*100}%`}})}),I.includes("popularity")&&f.jsx("span",{className:"track-num",onClick:g=>{g.stopPropagation(),ye("popularity",[a.popularity,a.popularity])},children:a.popularity||"-"}),I.includes("key")&&f.jsx("span",{className:"track-key",onClick:g=>{g.stopPropagation(),ye("key",String(a.key))},children:a.key!=null?Hu[a.key]:"-"}),f.jsx("button",{className:"track-queue",onClick:g=>d(g,a.spotify_id),title:"queue",children:"+"})]},a.spotify_id))}),Me&&f.jsx("div",{className:"selection-box",style:{left:Me.x,top:Me.y,width:Me.width,height:Me.height}}),U&&f.jsx("div",{className:"detail-overlay",onClick:()=>te(null),children:f.jsxs("div",{className:"detail",onClick:a=>a.stopPropagation(),children:[f.jsx("button",{className:"detail-close",onClick:()=>te(null),children:"x"}),f.jsx("h2",{children:U.name}),f.jsx("div",{className:"detail-artists",children:Bt(U.artists)}),f.jsx("div",{className:"detail-album",children:U.album_name}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"tempo"}),f.jsx("span",{children:U.tempo?Math.round(U.tempo)+" bpm":"-"})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"key"}),f.jsxs("span",{children:[U.key!=null?Hu[U.key]:"-"," ",U.mode===1?"major":U.mode===0?"minor":""]})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"danceability"}),f.jsx("span",{children:Et(U.danceability)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"energy"}),f.jsx("span",{children:Et(U.energy)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"valence"}),f.jsx("span",{children:Et(U.valence)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"acousticness"}),f.jsx("span",{children:Et(U.acousticness)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"instrumentalness"}),f.jsx("span",{children:Et(U.instrumentalness)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"speechiness"}),f.jsx("span",{children:Et(U.speechiness)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"liveness"}),f.jsx("span",{children:Et(U.liveness)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"loudness"}),f.jsx("span",{children:U.loudness?U.loudness.toFixed(1)+" dB":"-"})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"duration"}),f.jsx("span",{children:Nt(U.duration_ms)})]}),f.jsxs("div",{className:"detail-row",children:[f.jsx("span",{className:"detail-label",children:"popularity"}),f.jsx("span",{children:U.popularity||"-"})]}),f.jsx("div",{className:"detail-actions",children:f.jsx("button",{onClick:a=>d(a,U.spotify_id),children:"queue"})})]})}),(ee==null?void 0:ee.item)&&f.jsxs("div",{className:"player-bar",children:[f.jsx("div",{className:"player-progress-bar",onClick:async a=>{const g=a.currentTarget.getBoundingClientRect(),V=(a.clientX-g.left)/g.width,ke=Math.floor(V*ee.item.duration_ms);await fetch(`/api/player/seek/${ke}`,{method:"POST"})},children:f.jsx("div",{className:"player-progress-fill",style:{width:`${(ee.progress_ms||0)/(ee.item.duration_ms||1)*100}%`}})}),f.jsxs("div",{className:"player-times",children:[f.jsx("span",{children:Nt(ee.progress_ms)}),f.jsx("span",{children:Nt(ee.item.duration_ms)})]}),f.jsxs("div",{className:"player-main",children:[f.jsxs("div",{className:"player-track",children:[f.jsx("span",{className:"player-name",children:ee.item.name}),f.jsx("span",{className:"player-artist",children:(Zn=ee.item.artists)==null?void 0:Zn.map(a=>a.name).join(", ")})]}),f.jsxs("div",{className:"player-controls",children:[f.jsx("button",{onClick:H,children:"|<"}),f.jsx("button",{onClick:y,children:ee.is_playing?"||":"|>"}),f.jsx("button",{onClick:$,children:">|"})]})]})]})]})}Bf.createRoot(document.getElementById("root")).render(f.jsx(b.StrictMode,{children:f.jsx(Kf,{})}));
@@ -1,3 +1,4 @@
+-- schema.sql from the first release, before any columns were added or migrations existed
 CREATE TABLE IF NOT EXISTS tracks (
   spotify_id TEXT PRIMARY KEY,
   recco_id TEXT,
@@ -46,3 +47,12 @@ CREATE TABLE IF NOT EXISTS config (
   key TEXT PRIMARY KEY,
   value TEXT
 );
+
+-- a library synced by the first release, one track with features and one without
+INSERT INTO tracks (spotify_id, name, artists, album_name, sources, tempo, key, mode, energy, unavailable, first_seen)
+VALUES ('4uLU6hMCjMI75M1A2tKUQC', 'Bjørnen sover', '["Kari Bremnes"]', 'Norwegian Mood', '["liked","Morning","album:1lGMbRKOnGXvPzHN9nTxJD"]', 124.0, 7, 0, 0.8, 0, '2024-01-01T00:00:00+00:00');
+INSERT INTO tracks (spotify_id, name, artists, sources, unavailable, first_seen)
+VALUES ('0eGsygTp906u18L0Oimnem', 'Ingen features', '["Someone"]', '["liked"]', 0, '2024-01-01T00:00:00+00:00');
+INSERT INTO sync_log (started_at, finished_at, tracks_added, tracks_updated, tracks_unavailable)
+VALUES ('2024-01-01T00:00:00+00:00', '2024-01-01T00:05:00+00:00', 2, 0, 0);
+INSERT INTO config (key, value) VALUES ('spotify_refresh_token', 'token');
@@ -55,7 +55,13 @@ const DEFAULT_FILTERS = {
   genres: [],
 }
 
-function MultiSelect({ options, selected, onChange, label, onClear }) {
+// liked and the playlists a track is in, as filter values with a name to show
+function trackSources(track) {
+  const liked = track.sources && JSON.parse(track.sources).includes('liked')
+  return [...(liked ? [{ id: 'liked', name: 'liked' }] : []), ...(track.playlists || [])]
+}
+
+function MultiSelect({ options, labels, selected, onChange, label, onClear }) {
   const [open, setOpen] = useState(false)
   const [search, setSearch] = useState('')
   const ref = useRef(null)
@@ -70,8 +76,9 @@ function MultiSelect({ options, selected, onChange, label, onClear }) {
     return () => document.removeEventListener('mousedown', handleClickOutside)
   }, [])
 
+  const labelOf = o => (labels && labels[o]) || o
   const filtered = (options || []).filter(o => 
-    o.toLowerCase().includes(search.toLowerCase())
+    labelOf(o).toLowerCase().includes(search.toLowerCase())
   )
 
   const toggle = (item) => {
@@ -114,7 +121,7 @@ function MultiSelect({ options, selected, onChange, label, onClear }) {
                   checked={selected.includes(opt)}
                   onChange={() => toggle(opt)}
                 />
-                {opt}
+                {labelOf(opt)}
               </label>
             ))}
           </div>
@@ -158,7 +165,9 @@ function RangeSlider({ min, max, step, value, onChange, onClear, isActive }) {
 function App() {
   const [tracks, setTracks] = useState([])
   const [stats, setStats] = useState(null)
+  // "liked" plus playlist ids, with playlist names to show for the ids
   const [sources, setSources] = useState([])
+  const [sourceLabels, setSourceLabels] = useState({})
   const [genres, setGenres] = useState([])
 
   const [sort, setSort] = useState(() => {
@@ -199,14 +208,17 @@ function App() {
     if (filters.valence[0] > 0) params.set('valence_min', filters.valence[0])
     if (filters.valence[1] < 1) params.set('valence_max', filters.valence[1])
     if (filters.key) params.set('key', filters.key)
-    if (filters.sources.length) params.set('sources', filters.sources.join(','))
+    const liked = filters.sources.filter(s => s === 'liked')
+    const playlists = filters.sources.filter(s => s !== 'liked')
+    if (liked.length) params.set('sources', liked.join(','))
+    if (playlists.length) params.set('playlists', playlists.join(','))
     if (filters.genres.length) params.set('genres', filters.genres.join(','))
     params.set('sort', sort)
     params.set('limit', '500')
 
     const res = await fetch('/api/tracks?' + params.toString())
     const data = await res.json()
-    setTracks(data)
+    setTracks(data.tracks)
   }, [filters, sort])
 
   useEffect(() => {
@@ -229,7 +241,12 @@ function App() {
   useEffect(() => {
     fetch('/api/meta').then(r => r.json()).then(data => {
       setStats(data.stats)
-      setSources(data.sources || [])
+      const playlists = data.playlists || []
+      const options = [...(data.sources || []), ...playlists.map(p => p.id)]
+      setSources(options)
+      setSourceLabels(Object.fromEntries(playlists.map(p => [p.id, p.name])))
+      // saved filters from before playlists were picked by id hold names
+      setFilters(f => ({ ...f, sources: f.sources.filter(s => options.includes(s)) }))
       setGenres(data.genres || [])
     })
   }, [])
@@ -490,6 +507,7 @@ function App() {
             <label>{filterDef.label}</label>
             <MultiSelect
               options={filterDef.id === 'sources' ? sources : genres}
+              labels={filterDef.id === 'sources' ? sourceLabels : null}
               selected={filters[filterDef.id]}
               onChange={val => updateFilter(filterDef.id, val)}
               label={filterDef.label}
@@ -703,9 +721,9 @@ function App() {
             )}
             {visibleColumns.includes('sources') && (
               <span className="track-text" onMouseEnter={handleTextHover} onMouseLeave={handleTextLeave} onClick={e => e.stopPropagation()}>
-                {track.sources ? JSON.parse(track.sources).filter(s => !s.startsWith('album:')).map((src, i, arr) => (
-                  <span key={src}>
-                    <span className="track-link" onClick={() => updateFilter('sources', [...new Set([...filters.sources, src])])}>{src}</span>
+                {trackSources(track).length ? trackSources(track).map((src, i, arr) => (
+                  <span key={src.id}>
+                    <span className="track-link" onClick={() => updateFilter('sources', [...new Set([...filters.sources, src.id])])}>{src.name}</span>
                     {i < arr.length - 1 && ', '}
                   </span>
                 )) : '-'}